@@ -0,0 +1,241 @@
+use std::{
+    env, fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+};
+
+use adventure::{Command, Direction, ParseError, Verb};
+
+use crate::messages::{self, format, text};
+
+const CONFIG_HEADER: &str = "adventure-config: 1";
+
+/// Where the player's settings are kept: `$ADVENTURE_CONFIG` if set, otherwise
+/// `adventure/config` in the user's config directory.
+pub(crate) fn config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("ADVENTURE_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    let dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    Some(dir.join("adventure").join("config"))
+}
+
+/// Shortcuts the player has defined with `alias`, e.g. `k` for `clue Katie`.
+///
+/// They are stored in the config file as `alias: <name> = <command>` lines and
+/// written back whenever one is added or removed.
+pub(crate) struct Aliases {
+    path: Option<PathBuf>,
+    aliases: Vec<(String, String)>,
+}
+
+impl Aliases {
+    /// Aliases that are never saved.
+    pub(crate) fn new() -> Self {
+        Aliases {
+            path: None,
+            aliases: Vec::new(),
+        }
+    }
+
+    /// Aliases that are never saved, such as the ones a transcript was
+    /// recorded with.
+    pub(crate) fn unsaved(aliases: Vec<(String, String)>) -> Self {
+        Aliases {
+            path: None,
+            aliases,
+        }
+    }
+
+    /// Reads the aliases in the config file. A missing file just means there
+    /// are none yet.
+    pub(crate) fn load(path: PathBuf) -> Result<Self, String> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error.to_string()),
+        };
+        let mut aliases = Vec::new();
+        let mut lines = contents.lines().filter(|line| !line.trim().is_empty());
+        if let Some(header) = lines.next() {
+            if header != CONFIG_HEADER {
+                return Err("not an adventure config file".to_owned());
+            }
+        }
+        for line in lines {
+            let (name, command) = line
+                .strip_prefix("alias:")
+                .and_then(|alias| alias.split_once('='))
+                .ok_or_else(|| format!("unexpected line `{}`", line))?;
+            aliases.push((name.trim().to_owned(), command.trim().to_owned()));
+        }
+        Ok(Aliases {
+            path: Some(path),
+            aliases,
+        })
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.aliases
+            .iter()
+            .map(|(name, command)| (name.as_str(), command.as_str()))
+    }
+
+    /// Replaces an alias at the start of the line with its command. Aliases
+    /// are expanded once, so they can't refer to each other.
+    pub(crate) fn expand(&self, line: &str) -> String {
+        let line = line.trim();
+        let (first, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        match self
+            .aliases
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(first))
+        {
+            Some((_, command)) if rest.is_empty() => command.clone(),
+            Some((_, command)) => format!("{} {}", command, rest.trim_start()),
+            None => line.to_owned(),
+        }
+    }
+
+    /// Adds or replaces an alias and saves the config file.
+    pub(crate) fn define(&mut self, name: &str, command: &str) -> Result<(), String> {
+        if name.contains('=') {
+            return Err(text("alias.has-equals").to_owned());
+        }
+        if name.parse::<Verb>().is_ok()
+            || Direction::compass(name).is_some()
+            || name == "alias"
+            || messages::is_word(name)
+        {
+            return Err(format("alias.is-command", &[&name]));
+        }
+        if let Err(ParseError::UnknownCommand(_)) = messages::translate(command).parse::<Command>()
+        {
+            return Err(format("alias.not-command", &[&command]));
+        }
+        match self
+            .aliases
+            .iter_mut()
+            .find(|(existing, _)| existing.eq_ignore_ascii_case(name))
+        {
+            Some(alias) => alias.1 = command.to_owned(),
+            None => self.aliases.push((name.to_owned(), command.to_owned())),
+        }
+        self.save().map_err(|error| error.to_string())
+    }
+
+    /// Removes an alias and saves the config file. Returns whether there was
+    /// one to remove.
+    pub(crate) fn remove(&mut self, name: &str) -> Result<bool, String> {
+        let before = self.aliases.len();
+        self.aliases
+            .retain(|(existing, _)| !existing.eq_ignore_ascii_case(name));
+        if self.aliases.len() == before {
+            return Ok(false);
+        }
+        self.save().map_err(|error| error.to_string())?;
+        Ok(true)
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut contents = format!("{}\n", CONFIG_HEADER);
+        for (name, command) in &self.aliases {
+            contents.push_str(&format!("alias: {} = {}\n", name, command));
+        }
+        fs::write(path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_alias_runs_its_command_with_whatever_follows() {
+        let mut aliases = Aliases::new();
+        aliases.define("k", "clue Katie").unwrap();
+        aliases.define("grab", "take").unwrap();
+        assert_eq!(aliases.expand("K"), "clue Katie");
+        assert_eq!(aliases.expand(" grab  wine bottle"), "take wine bottle");
+        assert_eq!(aliases.expand("look"), "look");
+        // redefining replaces, whatever the case
+        aliases.define("K", "clue Harry").unwrap();
+        assert_eq!(
+            aliases.iter().collect::<Vec<_>>(),
+            [("k", "clue Harry"), ("grab", "take")]
+        );
+    }
+
+    #[test]
+    fn only_a_new_word_for_a_command_can_be_an_alias() {
+        let mut aliases = Aliases::new();
+        assert_eq!(
+            aliases.define("a=b", "look"),
+            Err(text("alias.has-equals").to_owned())
+        );
+        for name in ["take", "I", "n", "alias", "scores"] {
+            assert_eq!(
+                aliases.define(name, "look"),
+                Err(format("alias.is-command", &[&name])),
+                "{}",
+                name
+            );
+        }
+        assert_eq!(
+            aliases.define("k", "dance"),
+            Err(format("alias.not-command", &[&"dance"]))
+        );
+        // a command missing its argument is still a command: `grab wine`
+        assert_eq!(aliases.define("grab", "take"), Ok(()));
+        assert_eq!(aliases.iter().count(), 1);
+    }
+
+    #[test]
+    fn aliases_are_kept_in_the_config_file() {
+        let dir = env::temp_dir().join(format!("adventure-aliases-{}", std::process::id()));
+        let path = dir.join("adventure").join("config");
+
+        let mut aliases = Aliases::load(path.clone()).unwrap();
+        assert_eq!(aliases.iter().count(), 0);
+        aliases.define("k", "clue Katie").unwrap();
+        aliases.define("grab", "take").unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!(
+                "{}\nalias: k = clue Katie\nalias: grab = take\n",
+                CONFIG_HEADER
+            )
+        );
+
+        let mut loaded = Aliases::load(path.clone()).unwrap();
+        assert_eq!(
+            loaded.iter().collect::<Vec<_>>(),
+            [("k", "clue Katie"), ("grab", "take")]
+        );
+        assert_eq!(loaded.remove("K"), Ok(true));
+        assert_eq!(loaded.remove("K"), Ok(false));
+        let reloaded = Aliases::load(path.clone()).unwrap();
+        assert_eq!(reloaded.iter().collect::<Vec<_>>(), [("grab", "take")]);
+
+        fs::write(&path, "alias: k = look\n").unwrap();
+        assert_eq!(
+            Aliases::load(path.clone()).err().unwrap(),
+            "not an adventure config file"
+        );
+        fs::write(&path, format!("{}\nk = look\n", CONFIG_HEADER)).unwrap();
+        assert_eq!(
+            Aliases::load(path).err().unwrap(),
+            "unexpected line `k = look`"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
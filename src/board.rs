@@ -0,0 +1,259 @@
+use rand::Rng;
+
+use crate::{
+    command::Direction,
+    entity::Entity,
+    error::GameError,
+    room::{Room, RoomId},
+};
+
+/// Where each room of the house goes, given row by row.
+///
+/// A cell holding `None` leaves a gap, so houses don't have to be rectangular.
+/// Rooms next to each other on the grid are joined by compass exits; any other
+/// connection is added with [`Layout::add_exit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    width: usize,
+    height: usize,
+    cells: Vec<Option<String>>,
+    exits: Vec<(String, Direction, String)>,
+    descriptions: Vec<(String, String)>,
+}
+
+impl Layout {
+    pub fn new(width: usize, height: usize, cells: Vec<Option<String>>) -> Result<Self, GameError> {
+        let size = width.checked_mul(height).ok_or_else(|| {
+            GameError::Invalid(format!("a {}x{} layout is too big", width, height))
+        })?;
+        if cells.len() != size {
+            return Err(GameError::Invalid(format!(
+                "a {}x{} layout needs {} cells, found {}",
+                width,
+                height,
+                size,
+                cells.len()
+            )));
+        }
+        if cells.iter().all(Option::is_none) {
+            return Err(GameError::Invalid(
+                "a layout needs at least one room".to_owned(),
+            ));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+            exits: Vec::new(),
+            descriptions: Vec::new(),
+        })
+    }
+
+    /// Lays the rooms out as close to a square as possible, filling row by row.
+    pub fn from_names(names: Vec<String>) -> Result<Self, GameError> {
+        if names.is_empty() {
+            return Err(GameError::Invalid(
+                "a layout needs at least one room".to_owned(),
+            ));
+        }
+        let width = (names.len() as f64).sqrt().ceil() as usize;
+        let height = names.len().div_ceil(width);
+        let mut cells: Vec<Option<String>> = names.into_iter().map(Some).collect();
+        cells.resize(width * height, None);
+        Self::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn has_room(&self, name: &str) -> bool {
+        self.cells.iter().flatten().any(|cell| cell == name)
+    }
+
+    /// Adds a one-way exit from one room to another, e.g. a `passage` from the
+    /// Attic to the Backyard. Add a second exit for the way back.
+    pub fn add_exit(
+        &mut self,
+        from: &str,
+        direction: Direction,
+        to: &str,
+    ) -> Result<&mut Self, GameError> {
+        for name in [from, to] {
+            if !self.has_room(name) {
+                return Err(GameError::UnknownRoom(name.to_owned()));
+            }
+        }
+        self.exits.push((from.to_owned(), direction, to.to_owned()));
+        Ok(self)
+    }
+
+    /// Sets the text shown when the player looks around a room.
+    pub fn describe_room(&mut self, room: &str, description: &str) -> Result<&mut Self, GameError> {
+        if !self.has_room(room) {
+            return Err(GameError::UnknownRoom(room.to_owned()));
+        }
+        self.descriptions
+            .push((room.to_owned(), description.to_owned()));
+        Ok(self)
+    }
+
+    /// Shuffles the rooms within each row. Gaps stay where they are so a
+    /// shuffle never cuts a room off from the rest of the house.
+    pub(crate) fn shuffle_rooms(&mut self, rng: &mut impl Rng) {
+        let rn: usize = rng.gen_range(0..100);
+        for row in self.cells.chunks_mut(self.width) {
+            let occupied: Vec<usize> = (0..row.len()).filter(|&j| row[j].is_some()).collect();
+            for j in 0..occupied.len() {
+                let index = rn % occupied.len();
+                row.swap(occupied[j], occupied[index]);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    /// Every room in the house, in row order.
+    pub(crate) rooms: Vec<Room>,
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    /// The room in each cell of the grid, row by row.
+    pub(crate) cells: Vec<Option<RoomId>>,
+}
+
+impl Board {
+    pub fn from_layout(layout: Layout) -> Self {
+        let cells = layout
+            .cells
+            .into_iter()
+            .map(|name| name.map(Room::new))
+            .collect();
+        let mut board = Board::from_cells(layout.width, layout.height, cells);
+        // the layout only accepts exits and descriptions for its own rooms
+        for (from, direction, to) in layout.exits {
+            let (Some(from), Some(to)) =
+                (board.find_room_by_name(&from), board.find_room_by_name(&to))
+            else {
+                continue;
+            };
+            board.room_mut(from).set_exit(direction, to);
+        }
+        for (room, description) in layout.descriptions {
+            if let Some(room) = board.find_room_by_name(&room) {
+                board.room_mut(room).description = Some(description);
+            }
+        }
+        board
+    }
+
+    /// Builds a board from already furnished rooms and links grid neighbours.
+    pub(crate) fn from_cells(width: usize, height: usize, cells: Vec<Option<Room>>) -> Self {
+        let mut board = Board {
+            rooms: Vec::new(),
+            width,
+            height,
+            cells: Vec::new(),
+        };
+        for cell in cells {
+            board.cells.push(cell.map(|room| {
+                board.rooms.push(room);
+                RoomId(board.rooms.len() - 1)
+            }));
+        }
+        board.link_rooms();
+        board
+    }
+
+    pub fn room(&self, id: RoomId) -> &Room {
+        &self.rooms[id.0]
+    }
+
+    pub(crate) fn room_mut(&mut self, id: RoomId) -> &mut Room {
+        &mut self.rooms[id.0]
+    }
+
+    pub fn rooms(&self) -> impl Iterator<Item = (RoomId, &Room)> {
+        self.rooms
+            .iter()
+            .enumerate()
+            .map(|(i, room)| (RoomId(i), room))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The room at `row`, `column`, if that cell isn't a gap.
+    pub fn room_at(&self, row: usize, column: usize) -> Option<RoomId> {
+        if row < self.height && column < self.width {
+            self.cells[row * self.width + column]
+        } else {
+            None
+        }
+    }
+
+    /// The `(row, column)` of a room on the grid.
+    pub fn position(&self, id: RoomId) -> Option<(usize, usize)> {
+        let index = self.cells.iter().position(|&cell| cell == Some(id))?;
+        Some((index / self.width, index % self.width))
+    }
+
+    pub fn find_room_by_name(&self, name: &str) -> Option<RoomId> {
+        self.rooms()
+            .find(|(_, room)| room.name.eq_ignore_ascii_case(name))
+            .map(|(id, _)| id)
+    }
+
+    pub fn get_random_room(&self, rng: &mut impl Rng) -> RoomId {
+        loop {
+            let i: usize = rng.gen_range(0..self.height);
+            let j: usize = rng.gen_range(0..self.width);
+            if let Some(id) = self.room_at(i, j) {
+                return id;
+            }
+        }
+    }
+
+    pub fn find_room_for_character_by_name(&self, name: &str) -> Option<RoomId> {
+        self.rooms()
+            .find(|(_, room)| Entity::find_entity_by_name(name, &room.character_list).is_some())
+            .map(|(id, _)| id)
+    }
+
+    /// Connects every room to the rooms next to it on the grid.
+    fn link_rooms(&mut self) {
+        for i in 0..self.height {
+            for j in 0..self.width {
+                let Some(id) = self.room_at(i, j) else {
+                    continue;
+                };
+                let north = if i > 0 { self.room_at(i - 1, j) } else { None };
+                let south = self.room_at(i + 1, j);
+                let east = self.room_at(i, j + 1);
+                let west = if j > 0 { self.room_at(i, j - 1) } else { None };
+                let this_room = self.room_mut(id);
+                this_room.exits.clear();
+                let neighbours = [
+                    (Direction::North, north),
+                    (Direction::South, south),
+                    (Direction::East, east),
+                    (Direction::West, west),
+                ];
+                for (direction, neighbour) in neighbours {
+                    if let Some(neighbour) = neighbour {
+                        this_room.exits.push((direction, neighbour));
+                    }
+                }
+            }
+        }
+    }
+}
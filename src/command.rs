@@ -0,0 +1,522 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    config::{CLUE_COST, DROP_COST, GO_COST, TAKE_COST},
+    notes::Mark,
+};
+
+/// A player action understood by [`Game::apply`](crate::Game::apply).
+///
+/// Commands are usually parsed from a single line of input such as
+/// `go north`, `take wine bottle` or `clue Katie`. Most commands also have a
+/// short form, like `i` for `inventory`, and a compass direction on its own
+/// (`n`) goes that way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    List,
+    Look,
+    Map,
+    Notes,
+    /// Marks a line of the notebook, or rubs the mark out when there is none.
+    Mark(String, Option<Mark>),
+    Go(Direction),
+    Take(String),
+    Drop(String),
+    Inventory,
+    Clue(String),
+    /// The final accusation: a character, an item and a room, as typed.
+    Accuse(String),
+    Solution,
+    Undo,
+    Redo,
+    /// Parsed like any other command but carried out by the frontend, since
+    /// the engine has no files.
+    Save(String),
+    Load(String),
+}
+
+/// The first word of a command, without its argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verb {
+    Help,
+    List,
+    Look,
+    Map,
+    Notes,
+    Mark,
+    Go,
+    Take,
+    Drop,
+    Inventory,
+    Clue,
+    Accuse,
+    Solution,
+    Undo,
+    Redo,
+    Save,
+    Load,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The line was blank or did not start with a known command.
+    UnknownCommand(String),
+    /// The command needs an argument that was not given, e.g. a bare `take`.
+    MissingArgument(Verb),
+    /// A direction was expected but none was given.
+    InvalidDirection(String),
+}
+
+/// An entry in the table of commands. What `help` says about it is up to the
+/// frontend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandInfo {
+    pub verb: Verb,
+    /// The word typed to run the command.
+    pub name: &'static str,
+    /// Shorter words that run the same command, e.g. `i` for `inventory`.
+    pub short: &'static [&'static str],
+    /// Left out of the help table, or shown only as a hint.
+    pub secret: bool,
+}
+
+/// Every command the game understands, in the order `help` lists them.
+pub const COMMANDS: &[CommandInfo] = &[
+    CommandInfo {
+        verb: Verb::Help,
+        name: "help",
+        short: &["h", "?"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::List,
+        name: "list",
+        short: &["ls"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Look,
+        name: "look",
+        short: &["l"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Map,
+        name: "map",
+        short: &["m"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Notes,
+        name: "notes",
+        short: &["nb"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Mark,
+        name: "mark",
+        short: &["mk"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Go,
+        name: "go",
+        short: &["g"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Take,
+        name: "take",
+        short: &["t", "get"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Drop,
+        name: "drop",
+        short: &["d"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Inventory,
+        name: "inventory",
+        short: &["i", "inv"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Clue,
+        name: "clue",
+        short: &["c"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Accuse,
+        name: "accuse",
+        short: &["a"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Undo,
+        name: "undo",
+        short: &["u"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Redo,
+        name: "redo",
+        short: &["r"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Save,
+        name: "save",
+        short: &["sv"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Load,
+        name: "load",
+        short: &["ld"],
+        secret: false,
+    },
+    CommandInfo {
+        verb: Verb::Solution,
+        name: "game_solution",
+        short: &[],
+        secret: true,
+    },
+];
+
+impl Verb {
+    pub fn info(self) -> &'static CommandInfo {
+        COMMANDS
+            .iter()
+            .find(|info| info.verb == self)
+            .expect("every verb is in the command table")
+    }
+
+    fn takes_argument(self) -> bool {
+        matches!(
+            self,
+            Verb::Go
+                | Verb::Take
+                | Verb::Drop
+                | Verb::Clue
+                | Verb::Accuse
+                | Verb::Mark
+                | Verb::Save
+                | Verb::Load
+        )
+    }
+}
+
+impl FromStr for Verb {
+    type Err = ParseError;
+
+    fn from_str(buffer: &str) -> Result<Self, Self::Err> {
+        COMMANDS
+            .iter()
+            .find(|info| {
+                info.name.eq_ignore_ascii_case(buffer)
+                    || info
+                        .short
+                        .iter()
+                        .any(|short| short.eq_ignore_ascii_case(buffer))
+            })
+            .map(|info| info.verb)
+            .ok_or_else(|| ParseError::UnknownCommand(buffer.to_owned()))
+    }
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(buffer: &str) -> Result<Self, Self::Err> {
+        let mut words = buffer.split_whitespace();
+        let first = words.next().unwrap_or_default();
+        // a compass direction on its own, like `n`, means going that way
+        if let (Some(direction), None) = (Direction::compass(first), words.clone().next()) {
+            return Ok(Command::Go(direction));
+        }
+        let verb: Verb = first.parse()?;
+        let argument = words.collect::<Vec<&str>>().join(" ");
+
+        if argument.is_empty() && verb.takes_argument() {
+            return Err(ParseError::MissingArgument(verb));
+        }
+        if !argument.is_empty() && !verb.takes_argument() {
+            return Err(ParseError::UnknownCommand(buffer.trim().to_owned()));
+        }
+
+        Ok(match verb {
+            Verb::Help => Command::Help,
+            Verb::List => Command::List,
+            Verb::Look => Command::Look,
+            Verb::Map => Command::Map,
+            Verb::Notes => Command::Notes,
+            Verb::Mark => {
+                // the mark, if any, is the last word: `mark wine bottle x`
+                match argument.rsplit_once(' ') {
+                    Some((name, mark)) => match mark.parse() {
+                        Ok(mark) => Command::Mark(name.to_owned(), Some(mark)),
+                        Err(()) => Command::Mark(argument, None),
+                    },
+                    None => Command::Mark(argument, None),
+                }
+            }
+            Verb::Go => Command::Go(argument.parse()?),
+            Verb::Take => Command::Take(argument),
+            Verb::Drop => Command::Drop(argument),
+            Verb::Inventory => Command::Inventory,
+            Verb::Clue => Command::Clue(argument),
+            Verb::Accuse => Command::Accuse(argument),
+            Verb::Solution => Command::Solution,
+            Verb::Undo => Command::Undo,
+            Verb::Redo => Command::Redo,
+            Verb::Save => Command::Save(argument),
+            Verb::Load => Command::Load(argument),
+        })
+    }
+}
+
+impl Command {
+    pub fn verb(&self) -> Verb {
+        match self {
+            Command::Help => Verb::Help,
+            Command::List => Verb::List,
+            Command::Look => Verb::Look,
+            Command::Map => Verb::Map,
+            Command::Notes => Verb::Notes,
+            Command::Mark(..) => Verb::Mark,
+            Command::Go(_) => Verb::Go,
+            Command::Take(_) => Verb::Take,
+            Command::Drop(_) => Verb::Drop,
+            Command::Inventory => Verb::Inventory,
+            Command::Clue(_) => Verb::Clue,
+            Command::Accuse(_) => Verb::Accuse,
+            Command::Solution => Verb::Solution,
+            Command::Undo => Verb::Undo,
+            Command::Redo => Verb::Redo,
+            Command::Save(_) => Verb::Save,
+            Command::Load(_) => Verb::Load,
+        }
+    }
+
+    /// The action points the command uses up in a game that has them.
+    pub fn cost(&self) -> usize {
+        match self {
+            Command::Go(_) => GO_COST,
+            Command::Take(_) => TAKE_COST,
+            Command::Drop(_) => DROP_COST,
+            Command::Clue(_) => CLUE_COST,
+            _ => 0,
+        }
+    }
+}
+
+/// Writes the command the way it would be typed, e.g. `take Wine Bottle`.
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.verb().info().name)?;
+        match self {
+            Command::Go(direction) => write!(f, " {}", direction.to_string().to_lowercase()),
+            Command::Take(argument)
+            | Command::Drop(argument)
+            | Command::Clue(argument)
+            | Command::Accuse(argument)
+            | Command::Save(argument)
+            | Command::Load(argument) => write!(f, " {}", argument),
+            Command::Mark(name, Some(mark)) => write!(f, " {} {}", name, mark),
+            Command::Mark(name, None) => write!(f, " {}", name),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+    /// Any other way out of a room, such as a secret `passage` or the `stairs`.
+    /// The name is always lowercase.
+    Named(String),
+}
+
+impl Direction {
+    /// Parses `north`, `south`, `east` or `west`, or their first letter.
+    pub fn compass(word: &str) -> Option<Self> {
+        match word.to_ascii_lowercase().as_str() {
+            "north" | "n" => Some(Direction::North),
+            "south" | "s" => Some(Direction::South),
+            "east" | "e" => Some(Direction::East),
+            "west" | "w" => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(buffer: &str) -> Result<Self, Self::Err> {
+        let name = buffer.split_whitespace().collect::<Vec<&str>>().join(" ");
+        if name.is_empty() {
+            return Err(ParseError::InvalidDirection(buffer.to_owned()));
+        }
+        Ok(
+            Direction::compass(&name)
+                .unwrap_or_else(|| Direction::Named(name.to_ascii_lowercase())),
+        )
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            Direction::North => "North",
+            Direction::South => "South",
+            Direction::East => "East",
+            Direction::West => "West",
+            Direction::Named(name) => {
+                let mut chars = name.chars();
+                if let Some(first) = chars.next() {
+                    write!(f, "{}", first.to_uppercase())?;
+                }
+                return write!(f, "{}", chars.as_str());
+            }
+        };
+        write!(f, "{}", display)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<Command, ParseError> {
+        line.parse()
+    }
+
+    #[test]
+    fn commands_parse_by_name_or_short_form() {
+        assert_eq!(
+            parse("take wine bottle"),
+            Ok(Command::Take("wine bottle".to_owned()))
+        );
+        assert_eq!(
+            parse("  GET   wine   bottle "),
+            Ok(Command::Take("wine bottle".to_owned()))
+        );
+        assert_eq!(parse("i"), Ok(Command::Inventory));
+        assert_eq!(parse("Inventory"), Ok(Command::Inventory));
+        assert_eq!(parse("c Katie"), Ok(Command::Clue("Katie".to_owned())));
+        assert_eq!(parse("game_solution"), Ok(Command::Solution));
+        assert_eq!(
+            parse("dance"),
+            Err(ParseError::UnknownCommand("dance".to_owned()))
+        );
+        assert_eq!(parse(""), Err(ParseError::UnknownCommand(String::new())));
+    }
+
+    #[test]
+    fn a_compass_direction_on_its_own_goes_that_way() {
+        assert_eq!(parse("n"), Ok(Command::Go(Direction::North)));
+        assert_eq!(parse("West"), Ok(Command::Go(Direction::West)));
+        assert_eq!(parse("go s"), Ok(Command::Go(Direction::South)));
+        // only on its own: followed by anything it is not a command
+        assert_eq!(
+            parse("n x"),
+            Err(ParseError::UnknownCommand("n".to_owned()))
+        );
+    }
+
+    #[test]
+    fn any_other_direction_is_named_in_lowercase() {
+        assert_eq!(
+            parse("go Secret  Passage"),
+            Ok(Command::Go(Direction::Named("secret passage".to_owned())))
+        );
+        assert_eq!(
+            Direction::Named("secret passage".to_owned()).to_string(),
+            "Secret passage"
+        );
+        assert_eq!(
+            "  ".parse::<Direction>(),
+            Err(ParseError::InvalidDirection("  ".to_owned()))
+        );
+    }
+
+    #[test]
+    fn the_mark_is_the_last_word() {
+        assert_eq!(
+            parse("mark wine bottle x"),
+            Ok(Command::Mark("wine bottle".to_owned(), Some(Mark::Cleared)))
+        );
+        assert_eq!(
+            parse("mk Katie ?"),
+            Ok(Command::Mark("Katie".to_owned(), Some(Mark::Suspect)))
+        );
+        // without a mark the whole argument is the name, and the mark is rubbed out
+        assert_eq!(
+            parse("mark wine bottle"),
+            Ok(Command::Mark("wine bottle".to_owned(), None))
+        );
+        assert_eq!(parse("mark x"), Ok(Command::Mark("x".to_owned(), None)));
+    }
+
+    #[test]
+    fn missing_arguments_are_reported() {
+        for (line, verb) in [
+            ("take", Verb::Take),
+            ("go", Verb::Go),
+            ("clue  ", Verb::Clue),
+            ("mark", Verb::Mark),
+            ("save", Verb::Save),
+        ] {
+            assert_eq!(
+                parse(line),
+                Err(ParseError::MissingArgument(verb)),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn commands_without_arguments_take_none() {
+        for line in ["look around", "inventory now", "undo  twice"] {
+            assert_eq!(
+                parse(line),
+                Err(ParseError::UnknownCommand(line.to_owned())),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn commands_are_written_the_way_they_are_typed() {
+        for command in [
+            Command::Help,
+            Command::Notes,
+            Command::Mark("wine bottle".to_owned(), Some(Mark::Proven)),
+            Command::Mark("Katie".to_owned(), None),
+            Command::Go(Direction::East),
+            Command::Go(Direction::Named("secret passage".to_owned())),
+            Command::Take("Wine Bottle".to_owned()),
+            Command::Accuse("Katie knife attic".to_owned()),
+            Command::Solution,
+            Command::Save("before the attic".to_owned()),
+        ] {
+            assert_eq!(
+                parse(&command.to_string()),
+                Ok(command.clone()),
+                "{}",
+                command
+            );
+        }
+        assert_eq!(Command::Go(Direction::North).to_string(), "go north");
+    }
+}
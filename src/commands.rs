@@ -1,115 +1,337 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{collections::HashMap, fs, io, path::PathBuf};
+
+use adventure::{
+    config::SAVE_EXTENSION, names_as_string, Command, Direction, Event, Game, GameError,
+    GameStatus, SaveError,
+};
 
 use crate::{
-    config::PRINT_WIDTH, get_user_input, help_menus as menu, Game, Room,
+    aliases::Aliases,
+    completion::{exits, Completion},
+    input::Input,
+    messages::{self, format, text},
+    render::{render, Renderer},
+    scores::{HighScore, HighScores},
 };
 
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-    Invalid,
+/// Save files live in the working directory; a bare name gets the default
+/// extension.
+fn save_path(name: &str) -> PathBuf {
+    let mut path = PathBuf::from(name);
+    if path.extension().is_none() {
+        path.set_extension(SAVE_EXTENSION);
+    }
+    path
 }
 
-impl Direction {
-    fn get_direction(buffer: &str) -> Direction {
-        if buffer.eq("north") {
-            return Direction::North;
+/// Runs a command against the game, reading and writing the files of `save`
+/// and `load` that the engine leaves to the frontend. Given `replay_saves`,
+/// those games are kept there instead of in files.
+pub(crate) fn apply(
+    game: &mut Game,
+    command: Command,
+    replay_saves: Option<&mut HashMap<String, String>>,
+) -> Result<Vec<Event>, GameError> {
+    match command {
+        Command::Save(_) | Command::Load(_) if game.status() != GameStatus::InProgress => {
+            Err(GameError::GameOver)
         }
-        if buffer.eq("south") {
-            return Direction::South;
+        Command::Save(name) => {
+            let contents = game.to_save_string();
+            match replay_saves {
+                Some(saves) => {
+                    saves.insert(name.clone(), contents);
+                }
+                None => fs::write(save_path(&name), contents).map_err(SaveError::from)?,
+            }
+            Ok(vec![Event::Saved(name)])
         }
-        if buffer.eq("east") {
-            return Direction::East;
+        Command::Load(name) => {
+            let contents = match replay_saves {
+                Some(saves) => saves.get(&name).cloned().ok_or_else(|| {
+                    SaveError::from(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        "the replay saved no game by that name",
+                    ))
+                })?,
+                None => fs::read_to_string(save_path(&name)).map_err(SaveError::from)?,
+            };
+            *game = Game::from_save_str(&contents)?;
+            Ok(vec![Event::Loaded(name), Event::Room(game.room_view())])
         }
-        if buffer.eq("west") {
-            return Direction::West;
+        command => game.apply(command),
+    }
+}
+
+/// Applies a command and prints the outcome. When a name could mean several
+/// things the player is asked which one, and the command is tried again.
+pub(crate) fn run(
+    game: &mut Game,
+    input: &mut Input,
+    out: &mut dyn Renderer,
+    mut command: Command,
+) -> Result<Vec<Event>, GameError> {
+    loop {
+        let result = apply(game, command.clone(), input.replay_saves());
+        if let Err(GameError::Ambiguous { name, candidates }) = &result {
+            let Some(choice) = choose(input, out, name, candidates) else {
+                return result;
+            };
+            command = with_choice(command, choice);
+            continue;
         }
-        Direction::Invalid
+        render(out, game, &result);
+        return result;
+    }
+}
+
+/// The command again with the name the player picked in place of the one
+/// that was ambiguous.
+pub(crate) fn with_choice(command: Command, choice: String) -> Command {
+    match command {
+        Command::Take(_) => Command::Take(choice),
+        Command::Drop(_) => Command::Drop(choice),
+        Command::Clue(_) => Command::Clue(choice),
+        Command::Mark(_, mark) => Command::Mark(choice, mark),
+        command => command,
     }
 }
 
-fn print_hex_array(vec: Vec<u8>) {
-    // for char in vec.iter() {
-    //     print!("{}", *char as char);
-    // }
-    let out = String::from_utf8(vec).unwrap();
-    print!("{}", out);
+/// Lists the names `name` could mean and asks which one.
+pub(crate) fn ask_choice(out: &mut dyn Renderer, name: &str, candidates: &[String]) {
+    out.message(&format("prompt.choose", &[&name]));
+    for (n, candidate) in candidates.iter().enumerate() {
+        out.message(&format!("  {}. {}", n + 1, candidate));
+    }
+    out.prompt(text("prompt.choose-which"));
 }
 
-fn print_demarkcation_line() {
-    println!("{}", "═".repeat(PRINT_WIDTH));
+/// The candidate the player picked, by its number or its name.
+pub(crate) fn chosen(buffer: &str, candidates: &[String]) -> Option<String> {
+    let chosen = match buffer.parse::<usize>() {
+        Ok(n) if (1..=candidates.len()).contains(&n) => Some(&candidates[n - 1]),
+        _ => candidates.iter().find(|c| c.eq_ignore_ascii_case(buffer)),
+    };
+    chosen.cloned()
 }
-pub(crate) fn print_center(text: &str) {
-    let padlen = PRINT_WIDTH.saturating_sub(text.len()) / 2;
-    println!("{:indent$}{}", "", text, indent = padlen);
+
+/// Asks which of `candidates` the player meant by `name`. They can answer
+/// with the number or the name; a blank line gives up.
+fn choose(
+    input: &mut Input,
+    out: &mut dyn Renderer,
+    name: &str,
+    candidates: &[String],
+) -> Option<String> {
+    loop {
+        ask_choice(out, name, candidates);
+        input.complete_with(Completion::answers(candidates.to_vec()));
+        let buffer = input.get_user_input()?;
+        if buffer.is_empty() {
+            return None;
+        }
+        match chosen(&buffer, candidates) {
+            Some(choice) => return Some(choice),
+            None => out.message(&format!("\n{}\n", text("error.choose"))),
+        }
+    }
 }
 
-fn print_room(room: &RefCell<Room>) {
-    let room = room.borrow();
-    print_demarkcation_line();
-    print_center(&room.name);
-    print_demarkcation_line();
-    print_center("Rooms around you:");
-    print_center(room.around().as_str());
-    print_demarkcation_line();
-    print_center(format!("Items in {}:", room.name.as_str()).as_str());
-    print_center(room.item_list_as_string().as_str());
-    print_demarkcation_line();
-    print_center(format!("Characters in {}:", room.name.as_str()).as_str());
-    print_center(room.character_list_as_string().as_str());
-    print_demarkcation_line();
+/// `alias` lists the aliases, `alias <name>` removes one and
+/// `alias <name> <command>` adds or changes one.
+pub(crate) fn alias(aliases: &mut Aliases, out: &mut dyn Renderer, argument: &str) {
+    let (name, command) = argument
+        .split_once(char::is_whitespace)
+        .unwrap_or((argument, ""));
+    let command = command.trim();
+    if name.is_empty() {
+        if aliases.iter().next().is_none() {
+            out.message(&format!("{}\n", text("alias.none")));
+        }
+        for (name, command) in aliases.iter() {
+            out.message(&format!("{} = {}", name, command));
+        }
+        out.message("");
+        return;
+    }
+    let result = if command.is_empty() {
+        aliases.remove(name).map(|removed| match removed {
+            true => format("alias.removed", &[&name]),
+            false => format("alias.missing", &[&name]),
+        })
+    } else {
+        aliases
+            .define(name, command)
+            .map(|()| format("alias.defined", &[&name, &command]))
+    };
+    match result {
+        Ok(message) => out.message(&format!("{}\n", message)),
+        Err(error) => out.message(&format!("{}\n", format("alias.failed", &[&error]))),
+    }
 }
 
-/*
-Prints a hardcoded hex array of help commands
-*/
-pub(crate) fn help() {
-    // help_command_array is a array of chars in help_command_array.h
-    print_hex_array(menu::get_command_array());
+/// Once a game is over: scores a win, adds it to the high scores and shows
+/// them.
+pub(crate) fn game_over(game: &Game, title: &str, scores: &mut HighScores, out: &mut dyn Renderer) {
+    if let Some(score) = game.score() {
+        let entry = HighScore {
+            score,
+            scenario: title.to_owned(),
+        };
+        let rank = scores.add(entry).unwrap_or_else(|error| {
+            out.message(&format("scores.failed", &[&error]));
+            None
+        });
+        out.score(&score, rank);
+    }
+    out.scores(scores);
 }
 
-pub(crate) fn list() {
-    print_hex_array(menu::get_list_array());
+/// Asks for the next command, with the action points left if the game has
+/// them.
+pub(crate) fn command_prompt(game: &Game) -> String {
+    match game.action_points() {
+        Some(points) => format("prompt.command-points", &[&points]),
+        None => text("prompt.command").to_owned(),
+    }
 }
 
-pub(crate) fn look(room: &RefCell<Room>) {
-    print_room(room);
+/// Asks which way to go, naming any special exits of the current room.
+pub(crate) fn go_prompt(game: &Game) -> String {
+    let mut directions: Vec<String> = [Direction::North, Direction::South, Direction::East]
+        .iter()
+        .map(messages::direction_word)
+        .collect();
+    for (direction, _) in game.room_view().exits {
+        if let Direction::Named(name) = direction {
+            directions.push(name);
+        }
+    }
+    let west = messages::direction_word(&Direction::West);
+    format("prompt.go", &[&directions.join(", "), &west])
 }
-pub(crate) fn go(game: &mut Game) {
+
+pub(crate) fn go(game: &mut Game, input: &mut Input, out: &mut dyn Renderer) {
     loop {
-        println!("Enter north, south, east, or west:");
-        let buffer = get_user_input();
-
-        let direction: Direction = Direction::get_direction(&buffer);
-        let new_room: Option<Rc<RefCell<Room>>>;
-
-        {
-            let this_room = game.current_room.as_ref().unwrap().borrow();
-
-            let new_room_ref = match direction {
-                Direction::North => this_room.north.as_ref(),
-                Direction::South => this_room.south.as_ref(),
-                Direction::East => this_room.east.as_ref(),
-                Direction::West => this_room.west.as_ref(),
-                Direction::Invalid => {
-                    println!("\nRe-enter direction!\n");
-                    continue;
-                }
+        out.prompt(&go_prompt(game));
+        input.complete_with(Completion::answers(exits(game)));
+        let buffer = match input.get_user_input() {
+            Some(buffer) => buffer,
+            None => return,
+        };
+
+        match messages::translate_direction(&buffer).parse::<Direction>() {
+            Ok(direction) => {
+                let _ = run(game, input, out, Command::Go(direction));
+                break;
+            }
+            Err(_) => out.message(&format!("\n{}\n", text("error.direction"))),
+        }
+    }
+}
+
+pub(crate) fn take(game: &mut Game, input: &mut Input, out: &mut dyn Renderer) {
+    loop {
+        let room = game.room_view();
+        let mut buffer = String::new();
+        if !room.items.is_empty() {
+            out.message(&format("room.items", &[&room.name]));
+            out.message(&format!(
+                "{}\n",
+                names_as_string(&room.items, text("room.no-items"))
+            ));
+            out.prompt(text("prompt.take"));
+            input.complete_with(Completion::answers(room.items.clone()));
+            buffer = match input.get_user_input() {
+                Some(buffer) => buffer,
+                None => return,
             };
+        }
+
+        let result = run(game, input, out, Command::Take(buffer));
+        if !matches!(result, Err(GameError::UnknownItem(_))) {
+            break;
+        }
+    }
+}
 
-            new_room = new_room_ref.map(Rc::clone);
+pub(crate) fn drop(game: &mut Game, input: &mut Input, out: &mut dyn Renderer) {
+    loop {
+        let inventory = game.inventory();
+        let mut buffer = String::new();
+        if !inventory.is_empty() {
+            out.message(&format!(" {}", text("inventory.title")));
+            out.message(&format!(
+                "{}\n",
+                names_as_string(&inventory, text("inventory.empty"))
+            ));
+            out.prompt(text("prompt.drop"));
+            input.complete_with(Completion::answers(inventory.clone()));
+            buffer = match input.get_user_input() {
+                Some(buffer) => buffer,
+                None => return,
+            };
         }
 
-        match new_room {
-            Some(room_ref) => {
-                game.set_current_room(&room_ref);
-            }
-            None => {
-                println!("\nCannot go that way!\n");
-            }
+        let result = run(game, input, out, Command::Drop(buffer));
+        if !matches!(result, Err(GameError::UnknownItem(_))) {
+            break;
+        }
+    }
+}
+
+pub(crate) fn clue(game: &mut Game, input: &mut Input, out: &mut dyn Renderer) {
+    loop {
+        let characters = names_as_string(&game.npcs(), text("room.no-characters"));
+        out.prompt(&format("prompt.clue", &[&characters]));
+        input.complete_with(Completion::answers(game.npcs()));
+
+        let buffer = match input.get_user_input() {
+            Some(buffer) => buffer,
+            None => return,
+        };
+
+        let result = run(game, input, out, Command::Clue(buffer));
+        if !matches!(result, Err(GameError::UnknownCharacter(_))) {
+            break;
+        }
+    }
+}
+
+pub(crate) fn accuse(game: &mut Game, input: &mut Input, out: &mut dyn Renderer) {
+    loop {
+        out.prompt(text("prompt.accuse"));
+        input.complete_with(Completion::default());
+
+        // there is no taking an accusation back, so a blank line backs out
+        let buffer = match input.get_user_input() {
+            Some(buffer) if !buffer.is_empty() => buffer,
+            _ => return,
+        };
+
+        let result = run(game, input, out, Command::Accuse(buffer));
+        if !matches!(result, Err(GameError::BadAccusation(_))) {
+            break;
         }
-        break;
     }
 }
+
+pub(crate) fn save(game: &mut Game, input: &mut Input, out: &mut dyn Renderer) {
+    out.prompt(text("prompt.save"));
+    input.complete_with(Completion::default());
+    let buffer = match input.get_user_input() {
+        Some(buffer) => buffer,
+        None => return,
+    };
+    let _ = run(game, input, out, Command::Save(buffer));
+}
+
+pub(crate) fn load(game: &mut Game, input: &mut Input, out: &mut dyn Renderer) {
+    out.prompt(text("prompt.load"));
+    input.complete_with(Completion::default());
+    let buffer = match input.get_user_input() {
+        Some(buffer) => buffer,
+        None => return,
+    };
+    let _ = run(game, input, out, Command::Load(buffer));
+}
@@ -0,0 +1,139 @@
+use adventure::{Game, Verb, COMMANDS};
+use rustyline::{
+    completion::{Completer, Pair},
+    highlight::Highlighter,
+    hint::Hinter,
+    validate::Validator,
+    Context, Helper,
+};
+
+use crate::{aliases::Aliases, help_menus::FRONTEND_COMMANDS, messages};
+
+/// The words tab completion offers at the next prompt.
+///
+/// At the command prompt the first word completes to a command and the rest
+/// to whatever that command can act on right now: the exits of the room for
+/// `go`, the items in it for `take`, the inventory for `drop`, the characters
+/// for `clue` and every line of the notebook for `mark`. When the game asks a
+/// question instead, the whole answer completes from a fixed list.
+#[derive(Default)]
+pub(crate) struct Completion {
+    commands: bool,
+    aliases: Vec<String>,
+    directions: Vec<String>,
+    items: Vec<String>,
+    inventory: Vec<String>,
+    characters: Vec<String>,
+    notes: Vec<String>,
+    answers: Vec<String>,
+}
+
+impl Completion {
+    pub(crate) fn commands(game: &Game, aliases: &Aliases) -> Self {
+        let room = game.room_view();
+        Completion {
+            commands: true,
+            aliases: aliases.iter().map(|(name, _)| name.to_owned()).collect(),
+            directions: exits(game),
+            items: room.items,
+            inventory: game.inventory(),
+            characters: game.npcs(),
+            notes: game
+                .board()
+                .rooms()
+                .map(|(_, room)| room.name().to_owned())
+                .chain(game.game_items())
+                .chain(game.npcs())
+                .collect(),
+            answers: Vec::new(),
+        }
+    }
+
+    pub(crate) fn answers(answers: Vec<String>) -> Self {
+        Completion {
+            answers,
+            ..Completion::default()
+        }
+    }
+
+    fn arguments(&self, verb: Verb) -> &[String] {
+        match verb {
+            Verb::Go => &self.directions,
+            Verb::Take => &self.items,
+            Verb::Drop => &self.inventory,
+            Verb::Clue => &self.characters,
+            Verb::Mark => &self.notes,
+            _ => &[],
+        }
+    }
+}
+
+/// The ways out of the current room, as they would be typed after `go`.
+pub(crate) fn exits(game: &Game) -> Vec<String> {
+    game.room_view()
+        .exits
+        .iter()
+        .map(|(direction, _)| messages::direction_word(direction))
+        .collect()
+}
+
+/// Every candidate starting with `prefix`, ignoring case.
+fn complete<'a>(prefix: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<Pair> {
+    let prefix = prefix.to_lowercase();
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.to_lowercase().starts_with(&prefix))
+        .map(|candidate| Pair {
+            display: candidate.to_owned(),
+            replacement: candidate.to_owned(),
+        })
+        .collect()
+}
+
+impl Completer for Completion {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let line = &line[..pos];
+        let start = line.len() - line.trim_start().len();
+        if !self.commands {
+            let answers = self.answers.iter().map(String::as_str);
+            return Ok((start, complete(&line[start..], answers)));
+        }
+        match line[start..].split_once(char::is_whitespace) {
+            None => {
+                let names = COMMANDS
+                    .iter()
+                    .filter(|info| !info.secret)
+                    .map(|info| messages::word(info.name))
+                    .chain(FRONTEND_COMMANDS.iter().map(|name| messages::word(name)))
+                    .chain(self.aliases.iter().map(String::as_str));
+                Ok((start, complete(&line[start..], names)))
+            }
+            Some((verb, argument)) => {
+                let argument = argument.trim_start();
+                let arguments = match messages::translate(verb).parse::<Verb>() {
+                    Ok(verb) => self.arguments(verb),
+                    Err(_) => &[],
+                };
+                let candidates = arguments.iter().map(String::as_str);
+                Ok((pos - argument.len(), complete(argument, candidates)))
+            }
+        }
+    }
+}
+
+impl Hinter for Completion {
+    type Hint = String;
+}
+
+impl Highlighter for Completion {}
+
+impl Validator for Completion {}
+
+impl Helper for Completion {}
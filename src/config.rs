@@ -1,26 +1,12 @@
 pub static MAX_CLUES: usize = 10;
 
-pub static ROOM_NAMES: [&str; 9] = [
-    "Drawing Room",
-    "Backyard",
-    "Hallway",
-    "Attic",
-    "Katie's Room",
-    "Harry's Room",
-    "Peter's Room",
-    "Savanah's Room",
-    "Lexi's Room",
-];
-
-pub static NPC_NAMES: [&str; 5] = ["Katie", "Harry", "Peter", "Savanah", "Lexi"];
+pub static PRINT_WIDTH: usize = 90;
 
-pub static ITEM_NAMES: [&str; 6] = [
-    "Rubber Ducky",
-    "Hairdryer",
-    "Knife",
-    "Wine Bottle",
-    "Chair",
-    "Bedsheet",
-];
+pub static SAVE_EXTENSION: &str = "sav";
 
-pub static PRINT_WIDTH: usize = 90;
+/// What `go`, `take`, `drop` and `clue` cost in a game played with action
+/// points.
+pub static GO_COST: usize = 1;
+pub static TAKE_COST: usize = 1;
+pub static DROP_COST: usize = 1;
+pub static CLUE_COST: usize = 3;
@@ -0,0 +1,185 @@
+//! How much the answer to a clue gives away.
+
+use rand::Rng;
+use std::{fmt, str::FromStr};
+
+use crate::{
+    error::GameError,
+    game::{State, WinningState},
+};
+
+/// Picked when a game starts, with [`Game::set_difficulty`](crate::Game::set_difficulty).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    /// Every part of each answer, half as many clues again, and clues can be
+    /// undone.
+    Easy,
+    /// Every part of each answer.
+    #[default]
+    Normal,
+    /// Only how many parts of each answer are right.
+    Hard,
+    /// Only one part of each answer, picked at random.
+    Expert,
+}
+
+/// One of the three things an answer is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Room,
+    Item,
+    Character,
+}
+
+/// What the player is told about a clue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    /// Whether the room, the item and the character each match.
+    Each(WinningState),
+    /// How many of the three match, but not which.
+    Count(usize),
+    /// Whether one of the three matches.
+    One(Part, State),
+}
+
+impl Difficulty {
+    /// The clue budget of a game whose scenario allows `clues`.
+    pub(crate) fn clues(self, clues: usize) -> usize {
+        match self {
+            Difficulty::Easy => clues.saturating_add(clues / 2),
+            _ => clues,
+        }
+    }
+
+    pub(crate) fn feedback(self, state: WinningState, rng: &mut impl Rng) -> Feedback {
+        let parts = [
+            (Part::Room, state.room_match),
+            (Part::Item, state.item_match),
+            (Part::Character, state.character_match),
+        ];
+        match self {
+            Difficulty::Easy | Difficulty::Normal => Feedback::Each(state),
+            Difficulty::Hard => Feedback::Count(
+                parts
+                    .iter()
+                    .filter(|(_, state)| *state == State::Match)
+                    .count(),
+            ),
+            Difficulty::Expert => {
+                let (part, state) = parts[rng.gen_range(0..parts.len())];
+                Feedback::One(part, state)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+            Difficulty::Expert => "expert",
+        };
+        write!(f, "{}", display)
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = GameError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            "expert" => Ok(Difficulty::Expert),
+            _ => Err(GameError::Invalid(format!(
+                "unknown difficulty `{}`, expected easy, normal, hard or expert",
+                name
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    const ROOM_ONLY: WinningState = WinningState {
+        room_match: State::Match,
+        item_match: State::Miss,
+        character_match: State::Miss,
+    };
+
+    fn feedback(difficulty: Difficulty, seed: u64) -> Feedback {
+        difficulty.feedback(ROOM_ONLY, &mut StdRng::seed_from_u64(seed))
+    }
+
+    #[test]
+    fn easy_gives_half_as_many_clues_again() {
+        assert_eq!(Difficulty::Easy.clues(6), 9);
+        assert_eq!(Difficulty::Easy.clues(5), 7);
+        assert_eq!(Difficulty::Easy.clues(usize::MAX), usize::MAX);
+        for difficulty in [Difficulty::Normal, Difficulty::Hard, Difficulty::Expert] {
+            assert_eq!(difficulty.clues(6), 6);
+        }
+    }
+
+    #[test]
+    fn easy_and_normal_tell_every_part() {
+        assert_eq!(feedback(Difficulty::Easy, 1), Feedback::Each(ROOM_ONLY));
+        assert_eq!(feedback(Difficulty::Normal, 1), Feedback::Each(ROOM_ONLY));
+    }
+
+    #[test]
+    fn hard_tells_how_many_match() {
+        assert_eq!(feedback(Difficulty::Hard, 1), Feedback::Count(1));
+        let all = WinningState {
+            room_match: State::Match,
+            item_match: State::Match,
+            character_match: State::Match,
+        };
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(Difficulty::Hard.feedback(all, &mut rng), Feedback::Count(3));
+    }
+
+    #[test]
+    fn expert_tells_one_part_and_the_same_seed_picks_the_same_one() {
+        let mut picked = Vec::new();
+        for seed in 0..20 {
+            let one = feedback(Difficulty::Expert, seed);
+            assert_eq!(one, feedback(Difficulty::Expert, seed));
+            match one {
+                Feedback::One(Part::Room, state) => assert_eq!(state, State::Match),
+                Feedback::One(_, state) => assert_eq!(state, State::Miss),
+                other => panic!("expected one part, got {:?}", other),
+            }
+            picked.push(one);
+        }
+        // and over many seeds every part gets picked
+        for part in [Part::Room, Part::Item, Part::Character] {
+            assert!(picked
+                .iter()
+                .any(|one| matches!(one, Feedback::One(p, _) if *p == part)));
+        }
+    }
+
+    #[test]
+    fn difficulties_read_back_what_they_write() {
+        for difficulty in [
+            Difficulty::Easy,
+            Difficulty::Normal,
+            Difficulty::Hard,
+            Difficulty::Expert,
+        ] {
+            assert_eq!(
+                difficulty.to_string().parse::<Difficulty>().ok(),
+                Some(difficulty)
+            );
+        }
+        assert_eq!("HARD".parse::<Difficulty>().ok(), Some(Difficulty::Hard));
+        assert!("impossible".parse::<Difficulty>().is_err());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::error::GameError;
+
+/// How a name typed by the player matched a list of entities.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameMatch {
+    /// Exactly one entity fits; its index in the list.
+    One(usize),
+    /// Several entities fit equally well; their indices in list order.
+    Ambiguous(Vec<usize>),
+    None,
+}
+
+impl NameMatch {
+    fn from_indices(indices: Vec<usize>) -> Option<Self> {
+        match indices.len() {
+            0 => None,
+            1 => Some(NameMatch::One(indices[0])),
+            _ => Some(NameMatch::Ambiguous(indices)),
+        }
+    }
+}
+
+/// Optimal string alignment distance: the number of insertions, deletions,
+/// substitutions and swaps of neighbouring letters between `a` and `b`.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entity {
+    pub(crate) name: String,
+}
+
+impl Entity {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn entity_list_as_string(list: &[Entity], default: &str) -> String {
+        names_as_string(&names_of(list), default)
+    }
+
+    pub fn find_entity_by_name(name: &str, list: &[Entity]) -> Option<usize> {
+        list.iter().position(|x| x.name.eq_ignore_ascii_case(name))
+    }
+    /// Finds what the player meant by `name`, trying in turn an exact match,
+    /// the start of the name or of any word in it (`wine`, `ducky`), and
+    /// finally names within a typo or two (`kaite`). Case never matters.
+    pub fn match_entity_by_name(name: &str, list: &[Entity]) -> NameMatch {
+        let query = name
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .to_lowercase();
+        if query.is_empty() {
+            return NameMatch::None;
+        }
+        if let Some(index) = Entity::find_entity_by_name(&query, list) {
+            return NameMatch::One(index);
+        }
+        let names: Vec<String> = list.iter().map(|x| x.name.to_lowercase()).collect();
+
+        let prefixed = (0..names.len())
+            .filter(|&i| {
+                names[i].starts_with(&query)
+                    || names[i]
+                        .split_whitespace()
+                        .any(|word| word.starts_with(&query))
+            })
+            .collect();
+        if let Some(found) = NameMatch::from_indices(prefixed) {
+            return found;
+        }
+
+        // allow one mistake in short names and one more for every four letters
+        let query: Vec<char> = query.chars().collect();
+        let allowed = 1 + query.len() / 4;
+        let distances: Vec<usize> = names
+            .iter()
+            .map(|name| edit_distance(&query, &name.chars().collect::<Vec<char>>()))
+            .collect();
+        let closest = distances.iter().copied().min().unwrap_or(usize::MAX);
+        if closest > allowed {
+            return NameMatch::None;
+        }
+        let nearest = (0..names.len())
+            .filter(|&i| distances[i] == closest)
+            .collect();
+        NameMatch::from_indices(nearest).unwrap_or(NameMatch::None)
+    }
+    pub fn push_entity(list: &mut Vec<Entity>, item: Entity) {
+        list.push(item);
+    }
+    /// Inserts at `index`, or at the end if the list is shorter than that.
+    pub fn insert_entity(list: &mut Vec<Entity>, item: Entity, index: usize) {
+        list.insert(index.min(list.len()), item);
+    }
+    pub fn remove_entity(list: &mut Vec<Entity>, name: &str) -> Option<Entity> {
+        let position = Entity::find_entity_by_name(name, list);
+        position.map(|position| list.remove(position))
+    }
+    pub fn move_entity_by_name(
+        name: &str,
+        source: &mut Vec<Entity>,
+        dest: &mut Vec<Entity>,
+    ) -> Result<(), GameError> {
+        let item = Entity::remove_entity(source, name);
+        match item {
+            Some(item) => {
+                dest.push(item);
+                Ok(())
+            }
+            None => Err(GameError::NotFound(name.to_owned())),
+        }
+    }
+}
+
+/// Formats a list of names as `a | b | `, or `default` if the list is empty.
+pub fn names_as_string(names: &[String], default: &str) -> String {
+    let mut string: String = String::new();
+    fn form(str1: &str) -> String {
+        format!("{} | ", str1)
+    }
+    if names.is_empty() {
+        string.push_str(default);
+    }
+    for name in names.iter() {
+        string.push_str(&form(name));
+    }
+    string
+}
+
+pub(crate) fn names_of(list: &[Entity]) -> Vec<String> {
+    list.iter().map(|x| x.name.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(names: &[&str]) -> Vec<Entity> {
+        names
+            .iter()
+            .map(|name| Entity::new(name.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn exact_names_win_over_prefixes() {
+        let items = list(&["Knife", "Knife Block"]);
+        assert_eq!(
+            Entity::match_entity_by_name("knife", &items),
+            NameMatch::One(0)
+        );
+    }
+
+    #[test]
+    fn prefixes_match_the_name_or_any_word_in_it() {
+        let items = list(&["Rubber Ducky", "Wine Bottle", "Chair"]);
+        assert_eq!(
+            Entity::match_entity_by_name("rub", &items),
+            NameMatch::One(0)
+        );
+        assert_eq!(
+            Entity::match_entity_by_name("ducky", &items),
+            NameMatch::One(0)
+        );
+        assert_eq!(
+            Entity::match_entity_by_name("WINE", &items),
+            NameMatch::One(1)
+        );
+        assert_eq!(
+            Entity::match_entity_by_name("  wine   bottle ", &items),
+            NameMatch::One(1)
+        );
+    }
+
+    #[test]
+    fn typos_match_the_nearest_name() {
+        let npcs = list(&["Katie", "Harry", "Peter"]);
+        assert_eq!(
+            Entity::match_entity_by_name("kaite", &npcs),
+            NameMatch::One(0)
+        );
+        assert_eq!(
+            Entity::match_entity_by_name("hary", &npcs),
+            NameMatch::One(1)
+        );
+        assert_eq!(
+            Entity::match_entity_by_name("zzzzz", &npcs),
+            NameMatch::None
+        );
+    }
+
+    #[test]
+    fn several_equally_good_matches_are_ambiguous() {
+        let rooms = list(&["Katie's Room", "Harry's Room", "Attic"]);
+        assert_eq!(
+            Entity::match_entity_by_name("room", &rooms),
+            NameMatch::Ambiguous(vec![0, 1])
+        );
+        let npcs = list(&["Tom", "Tim"]);
+        assert_eq!(
+            Entity::match_entity_by_name("tam", &npcs),
+            NameMatch::Ambiguous(vec![0, 1])
+        );
+    }
+
+    #[test]
+    fn blank_names_match_nothing() {
+        let npcs = list(&["Katie"]);
+        assert_eq!(Entity::match_entity_by_name("   ", &npcs), NameMatch::None);
+    }
+}
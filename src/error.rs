@@ -0,0 +1,98 @@
+use std::{error, fmt};
+
+use crate::{command::Direction, save::SaveError};
+
+/// Why the engine could not do what it was asked.
+///
+/// Every fallible operation returns one of these instead of panicking, so a
+/// frontend can decide what to tell the player or whether to ask again.
+#[derive(Debug)]
+pub enum GameError {
+    /// No item by that name is in play, or it isn't where it was looked for.
+    UnknownItem(String),
+    UnknownCharacter(String),
+    UnknownRoom(String),
+    /// No room, item or character has that name.
+    UnknownName(String),
+    /// The name fits more than one entity; `candidates` are their full names.
+    Ambiguous {
+        name: String,
+        candidates: Vec<String>,
+    },
+    /// The current room has no exit that way.
+    NoExit(Direction),
+    /// Something that should have been in a room or the inventory wasn't.
+    NotFound(String),
+    /// `take` in a room with nothing in it.
+    NothingToTake,
+    EmptyInventory,
+    NothingToUndo,
+    NothingToRedo,
+    /// Every clue has been used; only an accusation is left.
+    NoCluesLeft,
+    /// The command costs more action points than are left.
+    NotEnoughPoints {
+        cost: usize,
+        left: usize,
+    },
+    /// The words of an accusation don't name a character, an item and a room.
+    BadAccusation(String),
+    /// The game has been won or lost and takes no more commands.
+    GameOver,
+    /// A layout or game was set up with missing or inconsistent parts.
+    Invalid(String),
+    Save(SaveError),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameError::UnknownItem(name) => write!(f, "there is no item called `{}`", name),
+            GameError::UnknownCharacter(name) => {
+                write!(f, "there is no character called `{}`", name)
+            }
+            GameError::UnknownRoom(name) => write!(f, "there is no room called `{}`", name),
+            GameError::UnknownName(name) => {
+                write!(f, "there is no room, item or character called `{}`", name)
+            }
+            GameError::Ambiguous { name, candidates } => {
+                write!(f, "`{}` could mean any of {}", name, candidates.join(", "))
+            }
+            GameError::NoExit(direction) => write!(f, "there is no exit {}", direction),
+            GameError::NotFound(name) => write!(f, "`{}` is not there", name),
+            GameError::NothingToTake => write!(f, "there is nothing to take"),
+            GameError::EmptyInventory => write!(f, "the inventory is empty"),
+            GameError::NothingToUndo => write!(f, "there is nothing to undo"),
+            GameError::NothingToRedo => write!(f, "there is nothing to redo"),
+            GameError::NoCluesLeft => write!(f, "there are no clues left"),
+            GameError::NotEnoughPoints { cost, left } => write!(
+                f,
+                "that costs {} action points but only {} are left",
+                cost, left
+            ),
+            GameError::BadAccusation(words) => write!(
+                f,
+                "`{}` does not name a character, an item and a room",
+                words
+            ),
+            GameError::GameOver => write!(f, "the game is over"),
+            GameError::Invalid(message) => write!(f, "{}", message),
+            GameError::Save(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl error::Error for GameError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            GameError::Save(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<SaveError> for GameError {
+    fn from(error: SaveError) -> Self {
+        GameError::Save(error)
+    }
+}
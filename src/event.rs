@@ -0,0 +1,66 @@
+use crate::{
+    command::Command,
+    difficulty::Feedback,
+    map::MapView,
+    notes::{Mark, Notes},
+    room::RoomView,
+};
+
+/// Something that happened in the game as the result of a [`Command`](crate::Command).
+///
+/// The engine never prints; frontends decide how each event is shown. Commands
+/// that fail report a [`GameError`](crate::GameError) instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Help,
+    /// Everything in play, for the `list` table.
+    List {
+        rooms: Vec<String>,
+        items: Vec<String>,
+        characters: Vec<String>,
+    },
+    Room(RoomView),
+    Map(MapView),
+    Notes(Notes),
+    /// A line of the notebook was marked, or its mark rubbed out.
+    Marked {
+        name: String,
+        mark: Option<Mark>,
+    },
+    ItemTaken(String),
+    ItemDropped(String),
+    Inventory(Vec<String>),
+    /// The answer to a guess: `character` was called to `room`, where the
+    /// player had `items` around them or in their inventory. How much the
+    /// answer tells depends on the difficulty.
+    Clue {
+        room: String,
+        character: String,
+        items: Vec<String>,
+        feedback: Feedback,
+    },
+    AttemptsRemaining(usize),
+    /// The player made their final accusation, with the full names of what
+    /// they named.
+    Accused {
+        character: String,
+        item: String,
+        room: String,
+    },
+    Won,
+    /// The accusation was wrong; a [`Event::Solution`] follows.
+    Lost,
+    /// The last action point was spent, which loses the game; a
+    /// [`Event::Solution`] follows.
+    Exhausted,
+    Solution {
+        room: String,
+        item: String,
+        character: String,
+    },
+    /// The command was taken back.
+    Undone(Command),
+    Redone(Command),
+    Saved(String),
+    Loaded(String),
+}
@@ -0,0 +1,1018 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::time::Duration;
+
+use crate::{
+    board::{Board, Layout},
+    command::{Command, Direction},
+    config::MAX_CLUES,
+    difficulty::{Difficulty, Feedback},
+    entity::{names_of, Entity, NameMatch},
+    error::GameError,
+    event::Event,
+    history::{Change, History},
+    notes::Notebook,
+    room::{Room, RoomId, RoomView},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WinningState {
+    pub room_match: State,
+    pub item_match: State,
+    pub character_match: State,
+}
+
+impl WinningState {
+    fn new() -> Self {
+        Self {
+            room_match: State::Miss,
+            item_match: State::Miss,
+            character_match: State::Miss,
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        matches!(self.room_match, State::Match)
+            && matches!(self.item_match, State::Match)
+            && matches!(self.character_match, State::Match)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Match,
+    Miss,
+}
+
+/// A clue the player asked and what it told them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    /// Where the character was called to.
+    pub room: String,
+    pub character: String,
+    /// The items in the room and in the inventory when the clue was asked.
+    pub items: Vec<String>,
+    pub feedback: Feedback,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameStatus {
+    InProgress,
+    Won,
+    Lost,
+}
+
+/// Works out which entity in `list` the player meant by `name`.
+pub(crate) fn resolve(
+    name: &str,
+    list: &[Entity],
+    unknown: fn(String) -> GameError,
+) -> Result<usize, GameError> {
+    match Entity::match_entity_by_name(name, list) {
+        NameMatch::One(index) => Ok(index),
+        NameMatch::Ambiguous(indices) => Err(GameError::Ambiguous {
+            name: name.to_owned(),
+            candidates: indices.iter().map(|&i| list[i].name.clone()).collect(),
+        }),
+        NameMatch::None => Err(unknown(name.to_owned())),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub(crate) board: Board,
+    pub(crate) game_items: Vec<Entity>,
+    pub(crate) npcs: Vec<Entity>,
+    pub(crate) inventory: Vec<Entity>,
+    pub(crate) current_room: RoomId,
+    /// The name of the character the player moves around the board.
+    pub(crate) avatar: String,
+    pub(crate) solution: Solution,
+    pub(crate) n_clue: usize,
+    pub(crate) max_clues: usize,
+    /// Every clue asked, even those taken back with `undo`; the score pays
+    /// for all of them.
+    pub(crate) clues_asked: usize,
+    /// How many times the player has gone to another room, counting moves
+    /// taken back with `undo` but not those made again with `redo`.
+    pub(crate) moves: usize,
+    /// The solution was shown with `game_solution`, so the game can't score.
+    pub(crate) revealed: bool,
+    /// How long the game has been played, as far as the frontend has said.
+    pub(crate) time: Duration,
+    pub(crate) status: GameStatus,
+    pub(crate) seed: u64,
+    pub(crate) history: History,
+    /// Whether `undo` can take back a clue, which also returns it to the budget.
+    pub(crate) clues_undoable: bool,
+    /// Every room the player has been in.
+    pub(crate) visited: BTreeSet<RoomId>,
+    /// Where the player last saw each character.
+    pub(crate) last_seen: BTreeMap<String, RoomId>,
+    pub(crate) notebook: Notebook,
+    /// Every clue asked and not undone, oldest first.
+    pub(crate) answers: Vec<Answer>,
+    pub(crate) difficulty: Difficulty,
+    /// The action points left, in a game that has them.
+    pub(crate) action_points: Option<usize>,
+}
+
+impl Game {
+    /// Starts a game with the rooms laid out as close to a square as possible.
+    pub fn new(
+        _room_names: Vec<&str>,
+        _item_names: Vec<&str>,
+        _npc_names: Vec<&str>,
+        avatar: Entity,
+        seed: u64,
+    ) -> Result<Self, GameError> {
+        let _room_names = _room_names.into_iter().map(String::from).collect();
+        Game::with_layout(
+            Layout::from_names(_room_names)?,
+            _item_names,
+            _npc_names,
+            avatar,
+            seed,
+        )
+    }
+
+    /// Fails if there are no items or no characters to make a solution from.
+    pub fn with_layout(
+        mut layout: Layout,
+        _item_names: Vec<&str>,
+        _npc_names: Vec<&str>,
+        avatar: Entity,
+        seed: u64,
+    ) -> Result<Self, GameError> {
+        if _item_names.is_empty() {
+            return Err(GameError::Invalid(
+                "a game needs at least one item".to_owned(),
+            ));
+        }
+        if _npc_names.is_empty() {
+            return Err(GameError::Invalid(
+                "a game needs at least one character".to_owned(),
+            ));
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let _item_names = _item_names.into_iter().map(String::from).collect();
+        let _npc_names = _npc_names.into_iter().map(String::from).collect();
+        fn create_game_items(_item_names: Vec<String>) -> Vec<Entity> {
+            construct_list(_item_names)
+        }
+        fn create_npcs(_npc_names: Vec<String>) -> Vec<Entity> {
+            construct_list(_npc_names)
+        }
+        fn construct_list(names: Vec<String>) -> Vec<Entity> {
+            names.into_iter().map(Entity::new).collect()
+        }
+        fn distribute_characters(board: &mut Board, entities: &[Entity], rng: &mut impl Rng) {
+            for entity in entities {
+                let random_room = board.get_random_room(rng);
+                Entity::push_entity(
+                    &mut board.room_mut(random_room).character_list,
+                    entity.clone(),
+                );
+            }
+        }
+        fn distribute_items(board: &mut Board, entities: &[Entity], rng: &mut impl Rng) {
+            for entity in entities {
+                let random_room = board.get_random_room(rng);
+                Entity::push_entity(&mut board.room_mut(random_room).item_list, entity.clone());
+            }
+        }
+        layout.shuffle_rooms(&mut rng);
+        let mut _board = Board::from_layout(layout);
+        let _game_items = create_game_items(_item_names);
+        let _npcs = create_npcs(_npc_names);
+        let _solution = Solution::new(&_board, &_game_items, &_npcs, &mut rng);
+        let _current_room = { _board.get_random_room(&mut rng) };
+        let _avatar = avatar.name.clone();
+        Entity::push_entity(&mut _board.room_mut(_current_room).character_list, avatar);
+        distribute_characters(&mut _board, &_npcs, &mut rng);
+        distribute_items(&mut _board, &_game_items, &mut rng);
+        let mut game = Game {
+            board: _board,
+            game_items: _game_items,
+            npcs: _npcs,
+            inventory: Vec::new(),
+            current_room: _current_room,
+            avatar: _avatar,
+            solution: _solution,
+            n_clue: 0,
+            max_clues: MAX_CLUES,
+            clues_asked: 0,
+            moves: 0,
+            revealed: false,
+            time: Duration::ZERO,
+            status: GameStatus::InProgress,
+            seed,
+            history: History::default(),
+            clues_undoable: false,
+            visited: BTreeSet::new(),
+            last_seen: BTreeMap::new(),
+            notebook: Notebook::default(),
+            answers: Vec::new(),
+            difficulty: Difficulty::default(),
+            action_points: None,
+        };
+        game.observe();
+        Ok(game)
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn game_items(&self) -> Vec<String> {
+        names_of(&self.game_items)
+    }
+
+    pub fn npcs(&self) -> Vec<String> {
+        names_of(&self.npcs)
+    }
+
+    pub fn inventory(&self) -> Vec<String> {
+        names_of(&self.inventory)
+    }
+
+    pub fn current_room(&self) -> &Room {
+        self.board.room(self.current_room)
+    }
+
+    pub fn current_room_id(&self) -> RoomId {
+        self.current_room
+    }
+
+    pub fn room_view(&self) -> RoomView {
+        self.current_room().view(&self.board)
+    }
+
+    pub fn solution(&self) -> &Solution {
+        &self.solution
+    }
+
+    pub fn clues_used(&self) -> usize {
+        self.n_clue
+    }
+
+    pub fn max_clues(&self) -> usize {
+        self.max_clues
+    }
+
+    /// Every clue asked and not undone, oldest first.
+    pub fn answers(&self) -> &[Answer] {
+        &self.answers
+    }
+
+    pub fn moves(&self) -> usize {
+        self.moves
+    }
+
+    /// How long the game has been played, across every session it was saved
+    /// and loaded in.
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Adds time measured by the frontend to the game's clock, which goes
+    /// into saves and the score.
+    pub fn pass_time(&mut self, time: Duration) {
+        self.time += time;
+    }
+
+    pub fn clues_undoable(&self) -> bool {
+        self.clues_undoable
+    }
+
+    pub fn set_clues_undoable(&mut self, undoable: bool) {
+        self.clues_undoable = undoable;
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    /// Sets up a new game for `difficulty`: how much each clue tells, how
+    /// many clues there are and whether they can be undone. Call it once,
+    /// before the first command.
+    pub fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+        self.max_clues = difficulty.clues(self.max_clues);
+        self.clues_undoable = difficulty == Difficulty::Easy;
+    }
+
+    pub fn action_points(&self) -> Option<usize> {
+        self.action_points
+    }
+
+    /// Gives the game a budget of action points that `go`, `take`, `drop`
+    /// and `clue` are paid from, or takes it away. The game is lost when the
+    /// last point is spent.
+    pub fn set_action_points(&mut self, points: Option<usize>) {
+        self.action_points = points;
+    }
+
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    /// The seed this game was generated from; passing it back to
+    /// [`Game::new`] with the same names rebuilds the same game.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Runs a single command against the game and reports what happened.
+    ///
+    /// Once the game has been won or lost every command fails with
+    /// [`GameError::GameOver`]. `save` and `load` always fail: the frontend
+    /// keeps the files, using [`Game::to_save_string`] and
+    /// [`Game::from_save_str`].
+    pub fn apply(&mut self, command: Command) -> Result<Vec<Event>, GameError> {
+        if self.status != GameStatus::InProgress {
+            return Err(GameError::GameOver);
+        }
+        let cost = command.cost();
+        if let Some(left) = self.action_points {
+            if cost > left {
+                return Err(GameError::NotEnoughPoints { cost, left });
+            }
+        }
+        let mut events = match command {
+            Command::Help => vec![Event::Help],
+            Command::List => vec![Event::List {
+                rooms: self
+                    .board
+                    .rooms()
+                    .map(|(_, room)| room.name.clone())
+                    .collect(),
+                items: self.game_items(),
+                characters: self.npcs(),
+            }],
+            Command::Look => vec![Event::Room(self.room_view())],
+            Command::Map => vec![Event::Map(self.map_view())],
+            Command::Notes => vec![Event::Notes(self.notes())],
+            Command::Mark(name, mark) => self.mark(&name, mark)?,
+            Command::Go(direction) => self.go(direction)?,
+            Command::Take(name) => self.take_item(&name)?,
+            Command::Drop(name) => self.drop_item(&name)?,
+            Command::Inventory => vec![Event::Inventory(self.inventory())],
+            Command::Clue(name) => self.clue(&name)?,
+            Command::Accuse(words) => self.accuse(&words)?,
+            Command::Solution => {
+                self.revealed = true;
+                vec![self.solution_event()]
+            }
+            Command::Undo => self.undo()?,
+            Command::Redo => self.redo()?,
+            // the engine has no files; see [`Game::to_save_string`]
+            Command::Save(_) | Command::Load(_) => {
+                return Err(GameError::Invalid(
+                    "saving and loading are up to the frontend".to_owned(),
+                ))
+            }
+        };
+        if let Some(points) = self.action_points.as_mut().filter(|_| cost > 0) {
+            // spent for good: undoing a move doesn't unsee the rooms it showed
+            *points -= cost;
+            if *points == 0 {
+                self.history.clear();
+                self.status = GameStatus::Lost;
+                events.push(Event::Exhausted);
+                events.push(self.solution_event());
+            }
+        }
+        self.observe();
+        Ok(events)
+    }
+
+    fn solution_event(&self) -> Event {
+        Event::Solution {
+            room: self.board.room(self.solution.room).name.clone(),
+            item: self.solution.item.clone(),
+            character: self.solution.character.clone(),
+        }
+    }
+
+    fn go(&mut self, direction: Direction) -> Result<Vec<Event>, GameError> {
+        let to = self
+            .current_room()
+            .neighbour(&direction)
+            .ok_or_else(|| GameError::NoExit(direction.clone()))?;
+        let change = Change::Moved {
+            from: self.current_room,
+            to,
+            index: Entity::find_entity_by_name(&self.avatar, &self.current_room().character_list)
+                .ok_or_else(|| GameError::NotFound(self.avatar.clone()))?,
+        };
+        self.play(&change)?;
+        self.moves += 1;
+        self.history.record(Command::Go(direction), vec![change]);
+        Ok(vec![Event::Room(self.room_view())])
+    }
+
+    fn take_item(&mut self, name: &str) -> Result<Vec<Event>, GameError> {
+        let items = &self.current_room().item_list;
+        if items.is_empty() {
+            return Err(GameError::NothingToTake);
+        }
+        let index = resolve(name, items, GameError::UnknownItem)?;
+        let name = items[index].name.clone();
+        let change = Change::Took {
+            name: name.clone(),
+            room: self.current_room,
+            index,
+        };
+        self.play(&change)?;
+        self.history
+            .record(Command::Take(name.clone()), vec![change]);
+        Ok(vec![Event::ItemTaken(name)])
+    }
+
+    fn drop_item(&mut self, name: &str) -> Result<Vec<Event>, GameError> {
+        if self.inventory.is_empty() {
+            return Err(GameError::EmptyInventory);
+        }
+        let index = resolve(name, &self.inventory, GameError::UnknownItem)?;
+        let name = self.inventory[index].name.clone();
+        let change = Change::Dropped {
+            name: name.clone(),
+            room: self.current_room,
+            index,
+        };
+        self.play(&change)?;
+        self.history
+            .record(Command::Drop(name.clone()), vec![change]);
+        Ok(vec![Event::ItemDropped(name)])
+    }
+
+    fn clue(&mut self, name: &str) -> Result<Vec<Event>, GameError> {
+        if self.n_clue >= self.max_clues {
+            return Err(GameError::NoCluesLeft);
+        }
+        let npc = resolve(name, &self.npcs, GameError::UnknownCharacter)?;
+        let name = self.npcs[npc].name.clone();
+        let room = self
+            .board
+            .find_room_for_character_by_name(&name)
+            .ok_or_else(|| GameError::NotFound(name.clone()))?;
+
+        let mut changes = Vec::new();
+        if room != self.current_room {
+            changes.push(Change::Summoned {
+                index: Entity::find_entity_by_name(&name, &self.board.room(room).character_list)
+                    .ok_or_else(|| GameError::NotFound(name.clone()))?,
+                name: name.clone(),
+                from: room,
+                to: self.current_room,
+            });
+            self.play(&changes[0])?;
+        }
+
+        let win_state: WinningState = self.get_win_state();
+
+        changes.push(Change::ClueUsed);
+        self.play(&Change::ClueUsed)?;
+        self.clues_asked += 1;
+
+        let room = self.current_room();
+        let items: Vec<String> = names_of(&room.item_list)
+            .into_iter()
+            .chain(self.inventory())
+            .collect();
+        // the same game always picks the same parts to tell about
+        let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(self.n_clue as u64));
+        let feedback: Feedback = self.difficulty.feedback(win_state, &mut rng);
+        let before = self.notebook.deduced.clone();
+        self.take_notes(&feedback, &items);
+        changes.push(Change::Deduced {
+            before,
+            after: self.notebook.deduced.clone(),
+        });
+        let answer = Answer {
+            room: self.current_room().name.clone(),
+            character: name.clone(),
+            items,
+            feedback,
+        };
+        changes.push(Change::Answered(answer.clone()));
+        self.play(&Change::Answered(answer.clone()))?;
+        if self.clues_undoable {
+            self.history.record(Command::Clue(name.clone()), changes);
+        } else {
+            self.history.clear();
+        }
+        // a clue only answers; winning takes an accusation
+        Ok(vec![
+            Event::Clue {
+                room: answer.room,
+                character: answer.character,
+                items: answer.items,
+                feedback: answer.feedback,
+            },
+            Event::AttemptsRemaining(self.max_clues - self.n_clue),
+        ])
+    }
+
+    /// The one final guess. `words` name a character, an item and a room in
+    /// that order, e.g. `Katie Wine Bottle Attic`; every way of splitting
+    /// them into three names is tried until one fits.
+    fn accuse(&mut self, words: &str) -> Result<Vec<Event>, GameError> {
+        let words: Vec<&str> = words
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|word| !word.is_empty())
+            .collect();
+        let rooms: Vec<Entity> = self
+            .board
+            .rooms()
+            .map(|(_, room)| Entity::new(room.name.clone()))
+            .collect();
+        let find = |words: &[&str], list: &[Entity]| match Entity::match_entity_by_name(
+            &words.join(" "),
+            list,
+        ) {
+            NameMatch::One(index) => Some(list[index].name.clone()),
+            _ => None,
+        };
+        let n = words.len();
+        let accusation = (1..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .find_map(|(i, j)| {
+                Some((
+                    find(&words[..i], &self.npcs)?,
+                    find(&words[i..j], &self.game_items)?,
+                    find(&words[j..], &rooms)?,
+                ))
+            });
+        let Some((character, item, room)) = accusation else {
+            return Err(GameError::BadAccusation(words.join(" ")));
+        };
+
+        self.history.clear();
+        let solution = &self.solution;
+        let correct = character == solution.character
+            && item == solution.item
+            && room == self.board.room(solution.room).name;
+        let mut events = vec![Event::Accused {
+            character,
+            item,
+            room,
+        }];
+        if correct {
+            self.status = GameStatus::Won;
+            events.push(Event::Won);
+        } else {
+            self.status = GameStatus::Lost;
+            events.push(Event::Lost);
+            events.push(self.solution_event());
+        }
+        Ok(events)
+    }
+
+    fn undo(&mut self) -> Result<Vec<Event>, GameError> {
+        let step = self
+            .history
+            .undo()
+            .cloned()
+            .ok_or(GameError::NothingToUndo)?;
+        for change in step.changes.iter().rev() {
+            self.revert(change)?;
+        }
+        Ok(vec![
+            Event::Undone(step.command),
+            Event::Room(self.room_view()),
+        ])
+    }
+
+    fn redo(&mut self) -> Result<Vec<Event>, GameError> {
+        let step = self
+            .history
+            .redo()
+            .cloned()
+            .ok_or(GameError::NothingToRedo)?;
+        for change in &step.changes {
+            self.play(change)?;
+        }
+        Ok(vec![
+            Event::Redone(step.command),
+            Event::Room(self.room_view()),
+        ])
+    }
+
+    /// Makes a change to the game.
+    fn play(&mut self, change: &Change) -> Result<(), GameError> {
+        match change {
+            Change::Moved { from, to, .. } => {
+                let avatar = self.avatar.clone();
+                self.move_character(&avatar, *from, *to, None)?;
+                self.current_room = *to;
+            }
+            Change::Summoned { name, from, to, .. } => {
+                self.move_character(name, *from, *to, None)?
+            }
+            Change::Took { name, room, .. } => Entity::move_entity_by_name(
+                name,
+                &mut self.board.room_mut(*room).item_list,
+                &mut self.inventory,
+            )?,
+            Change::Dropped { name, room, .. } => Entity::move_entity_by_name(
+                name,
+                &mut self.inventory,
+                &mut self.board.room_mut(*room).item_list,
+            )?,
+            Change::ClueUsed => self.n_clue += 1,
+            Change::Deduced { after, .. } => self.notebook.deduced = after.clone(),
+            Change::Answered(answer) => self.answers.push(answer.clone()),
+        }
+        Ok(())
+    }
+
+    /// Takes back a change made by [`Game::play`].
+    fn revert(&mut self, change: &Change) -> Result<(), GameError> {
+        let not_found = |name: &str| GameError::NotFound(name.to_owned());
+        match change {
+            Change::Moved { from, to, index } => {
+                let avatar = self.avatar.clone();
+                self.move_character(&avatar, *to, *from, Some(*index))?;
+                self.current_room = *from;
+            }
+            Change::Summoned {
+                name,
+                from,
+                to,
+                index,
+            } => self.move_character(name, *to, *from, Some(*index))?,
+            Change::Took { name, room, index } => {
+                let item = Entity::remove_entity(&mut self.inventory, name)
+                    .ok_or_else(|| not_found(name))?;
+                Entity::insert_entity(&mut self.board.room_mut(*room).item_list, item, *index);
+            }
+            Change::Dropped { name, room, index } => {
+                let items = &mut self.board.room_mut(*room).item_list;
+                let item = Entity::remove_entity(items, name).ok_or_else(|| not_found(name))?;
+                Entity::insert_entity(&mut self.inventory, item, *index);
+            }
+            Change::ClueUsed => self.n_clue = self.n_clue.saturating_sub(1),
+            Change::Deduced { before, .. } => self.notebook.deduced = before.clone(),
+            Change::Answered(_) => {
+                self.answers.pop();
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves a character between rooms, to `index` in the new room's list or
+    /// to the end of it.
+    fn move_character(
+        &mut self,
+        name: &str,
+        from: RoomId,
+        to: RoomId,
+        index: Option<usize>,
+    ) -> Result<(), GameError> {
+        let characters = &mut self.board.room_mut(from).character_list;
+        let character = Entity::remove_entity(characters, name)
+            .ok_or_else(|| GameError::NotFound(name.to_owned()))?;
+        let characters = &mut self.board.room_mut(to).character_list;
+        let index = index.unwrap_or(characters.len());
+        Entity::insert_entity(characters, character, index);
+        Ok(())
+    }
+
+    pub fn get_win_state(&self) -> WinningState {
+        let mut win_state = WinningState::new();
+        if self.current_room == self.solution.room {
+            win_state.room_match = State::Match;
+        }
+        let correct_item = &self.solution.item;
+        if Entity::find_entity_by_name(correct_item, &self.current_room().item_list).is_some()
+            || Entity::find_entity_by_name(correct_item, &self.inventory).is_some()
+        {
+            win_state.item_match = State::Match;
+        }
+        let correct_character = &self.solution.character;
+        if Entity::find_entity_by_name(correct_character, &self.current_room().character_list)
+            .is_some()
+        {
+            win_state.character_match = State::Match;
+        }
+        win_state
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub(crate) room: RoomId,
+    pub(crate) item: String,
+    pub(crate) character: String,
+}
+
+impl Solution {
+    fn new(board: &Board, items: &[Entity], characters: &[Entity], rng: &mut impl Rng) -> Self {
+        let room = { board.get_random_room(rng) };
+        let item = {
+            let rn: usize = rng.gen_range(0..items.len());
+            items[rn].name.clone()
+        };
+        let character = {
+            let rn: usize = rng.gen_range(0..characters.len());
+            characters[rn].name.clone()
+        };
+
+        Self {
+            room,
+            item,
+            character,
+        }
+    }
+
+    pub fn room(&self) -> RoomId {
+        self.room
+    }
+
+    pub fn item(&self) -> &str {
+        &self.item
+    }
+
+    pub fn character(&self) -> &str {
+        &self.character
+    }
+}
+
+impl fmt::Display for State {
+    // This trait requires `fmt` with this exact signature.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Write strictly the first element into the supplied output
+        // stream: `f`. Returns `fmt::Result` which indicates whether the
+        // operation succeeded or failed. Note that `write!` uses syntax which
+        // is very similar to `println!`.
+        let display = match *self {
+            State::Match => "MATCH",
+            State::Miss => "MISS",
+        };
+        write!(f, "{}", display)
+    }
+}
+impl fmt::Display for WinningState {
+    // This trait requires `fmt` with this exact signature.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Write strictly the first element into the supplied output
+        // stream: `f`. Returns `fmt::Result` which indicates whether the
+        // operation succeeded or failed. Note that `write!` uses syntax which
+        // is very similar to `println!`.
+        write!(
+            f,
+            "\nANSWER\n\
+                 ROOM: {} \n\
+                 ITEM: {} \n\
+            CHARACTER: {} \n\
+            ",
+            self.room_match, self.item_match, self.character_match,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::{CLUE_COST, GO_COST, TAKE_COST},
+        score::Score,
+    };
+
+    /// A four-room game with every item in the room the player starts in.
+    fn game() -> Game {
+        let mut game = Game::new(
+            vec!["Hall", "Attic", "Cellar", "Study"],
+            vec!["Knife", "Wine Bottle", "Rope"],
+            vec!["Katie", "Harry"],
+            Entity::new("Detective".to_owned()),
+            7,
+        )
+        .unwrap();
+        let ids: Vec<RoomId> = game.board.rooms().map(|(id, _)| id).collect();
+        for id in ids {
+            game.board.room_mut(id).item_list.clear();
+        }
+        let here = game.current_room;
+        game.board.room_mut(here).item_list = game.game_items.clone();
+        game
+    }
+
+    fn any_exit(game: &Game) -> Direction {
+        [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ]
+        .into_iter()
+        .find(|direction| game.current_room().neighbour(direction).is_some())
+        .expect("every room in a grid has a way out")
+    }
+
+    /// Everything `undo` and `redo` are meant to restore. What the player has
+    /// seen on the map stays known, the score still counts every move and
+    /// action points stay spent.
+    fn state(game: &Game) -> (Board, Vec<Entity>, RoomId) {
+        (
+            game.board.clone(),
+            game.inventory.clone(),
+            game.current_room,
+        )
+    }
+
+    #[test]
+    fn undo_and_redo_restore_the_exact_state() {
+        let mut game = game();
+        game.set_action_points(Some(20));
+        let before = state(&game);
+
+        for command in [
+            Command::Take("wine".to_owned()),
+            Command::Take("knife".to_owned()),
+            Command::Drop("wine".to_owned()),
+        ] {
+            game.apply(command).unwrap();
+        }
+        game.apply(Command::Go(any_exit(&game))).unwrap();
+        game.apply(Command::Drop("knife".to_owned())).unwrap();
+        let after = state(&game);
+
+        for _ in 0..5 {
+            game.apply(Command::Undo).unwrap();
+        }
+        assert_eq!(state(&game), before);
+        assert_eq!(
+            names_of(&game.current_room().item_list),
+            ["Knife", "Wine Bottle", "Rope"]
+        );
+        assert!(matches!(
+            game.apply(Command::Undo),
+            Err(GameError::NothingToUndo)
+        ));
+
+        for _ in 0..5 {
+            game.apply(Command::Redo).unwrap();
+        }
+        assert_eq!(state(&game), after);
+    }
+
+    #[test]
+    fn action_points_stay_spent_through_undo_and_redo() {
+        let mut game = game();
+        game.set_action_points(Some(20));
+        game.apply(Command::Take("rope".to_owned())).unwrap();
+        game.apply(Command::Go(any_exit(&game))).unwrap();
+        let left = 20 - TAKE_COST - GO_COST;
+        assert_eq!(game.action_points(), Some(left));
+        game.apply(Command::Undo).unwrap();
+        game.apply(Command::Undo).unwrap();
+        assert_eq!(game.action_points(), Some(left));
+        game.apply(Command::Redo).unwrap();
+        assert_eq!(game.action_points(), Some(left));
+    }
+
+    #[test]
+    fn a_command_that_costs_too_much_is_refused() {
+        let mut game = game();
+        game.set_action_points(Some(CLUE_COST - 1));
+        let before = game.clone();
+        assert!(matches!(
+            game.apply(Command::Clue("katie".to_owned())),
+            Err(GameError::NotEnoughPoints { cost, left })
+                if cost == CLUE_COST && left == CLUE_COST - 1
+        ));
+        assert_eq!(game, before);
+        // looking around is free
+        game.apply(Command::Look).unwrap();
+        assert_eq!(game.action_points(), Some(CLUE_COST - 1));
+    }
+
+    #[test]
+    fn spending_the_last_point_loses() {
+        let mut game = game();
+        game.set_action_points(Some(TAKE_COST));
+        let events = game.apply(Command::Take("rope".to_owned())).unwrap();
+        assert!(events.contains(&Event::Exhausted));
+        assert_eq!(game.status(), GameStatus::Lost);
+        assert_eq!(game.action_points(), Some(0));
+        assert!(matches!(
+            game.apply(Command::Undo),
+            Err(GameError::GameOver)
+        ));
+    }
+
+    fn accuse(game: &mut Game, words: &str) -> Result<Vec<Event>, GameError> {
+        game.apply(Command::Accuse(words.to_owned()))
+    }
+
+    #[test]
+    fn accusations_are_split_into_a_character_an_item_and_a_room() {
+        let mut game = game();
+        game.solution = Solution {
+            room: game.board.find_room_by_name("Attic").unwrap(),
+            item: "Wine Bottle".to_owned(),
+            character: "Harry".to_owned(),
+        };
+        let events = accuse(&mut game, "harry, wine bottle attic").unwrap();
+        assert_eq!(
+            events[0],
+            Event::Accused {
+                character: "Harry".to_owned(),
+                item: "Wine Bottle".to_owned(),
+                room: "Attic".to_owned(),
+            }
+        );
+        assert_eq!(events[1], Event::Won);
+        assert_eq!(game.status(), GameStatus::Won);
+    }
+
+    #[test]
+    fn a_wrong_accusation_loses() {
+        let mut game = game();
+        let wrong = if game.solution.character == "Katie" {
+            "Harry"
+        } else {
+            "Katie"
+        };
+        let words = format!("{} {} Study", wrong, game.solution.item);
+        assert!(accuse(&mut game, &words).unwrap().contains(&Event::Lost));
+        assert_eq!(game.status(), GameStatus::Lost);
+    }
+
+    #[test]
+    fn accusations_that_cannot_be_split_are_rejected() {
+        let mut game = game();
+        for words in [
+            "Katie",
+            "Katie Knife",
+            "Katie Knife Garage",
+            "Knife Katie Hall",
+        ] {
+            assert!(matches!(
+                accuse(&mut game, words),
+                Err(GameError::BadAccusation(_))
+            ));
+        }
+        assert_eq!(game.status(), GameStatus::InProgress);
+    }
+    #[test]
+    fn undoing_a_clue_returns_it_but_still_counts_it() {
+        let mut game = game();
+        game.set_clues_undoable(true);
+        game.apply(Command::Clue("katie".to_owned())).unwrap();
+        game.apply(Command::Undo).unwrap();
+        assert_eq!(game.clues_used(), 0);
+        assert_eq!(game.clues_asked, 1);
+    }
+
+    #[test]
+    fn undoing_a_clue_takes_back_its_answer() {
+        let mut game = game();
+        game.set_clues_undoable(true);
+        game.apply(Command::Clue("katie".to_owned())).unwrap();
+        let answers = game.answers().to_vec();
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].character, "Katie");
+        game.apply(Command::Undo).unwrap();
+        assert!(game.answers().is_empty());
+        game.apply(Command::Redo).unwrap();
+        assert_eq!(game.answers(), answers);
+    }
+
+    #[test]
+    fn undoing_a_move_still_counts_it_once() {
+        let mut game = game();
+        game.apply(Command::Go(any_exit(&game))).unwrap();
+        game.apply(Command::Undo).unwrap();
+        assert_eq!(game.moves(), 1);
+        game.apply(Command::Redo).unwrap();
+        assert_eq!(game.moves(), 1);
+    }
+
+    #[test]
+    fn only_a_fair_win_scores() {
+        let mut game = game();
+        game.pass_time(Duration::from_secs(90));
+        game.apply(Command::Go(any_exit(&game))).unwrap();
+        game.apply(Command::Clue("katie".to_owned())).unwrap();
+        assert_eq!(game.score(), None);
+
+        let mut won = game.clone();
+        won.status = GameStatus::Won;
+        assert_eq!(won.score(), Some(Score::new(1, 1, 90)));
+
+        let mut looked_up = game.clone();
+        looked_up.apply(Command::Solution).unwrap();
+        looked_up.status = GameStatus::Won;
+        assert_eq!(looked_up.score(), None);
+
+        let mut lost = game;
+        lost.status = GameStatus::Lost;
+        assert_eq!(lost.score(), None);
+    }
+}
@@ -1,217 +1,303 @@
-pub fn get_command_array() -> Vec<u8> {
-    let help_command_array = vec![
-        0xe2, 0x95, 0x94, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0xa6, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0xa6, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x97,
-        0x0a, 0xe2, 0x95, 0x91, 0x20, 0x23, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x20, 0x20, 0x20,
-        0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95, 0xa0, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0xac, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0xac, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0xa3, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x31, 0x2e, 0x20, 0xe2, 0x95,
-        0x91, 0x20, 0x68, 0x65, 0x6c, 0x70, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0xe2, 0x95, 0x91, 0x20, 0x50, 0x72, 0x69, 0x6e, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65,
-        0x20, 0x68, 0x65, 0x6c, 0x70, 0x20, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x2e, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x32,
-        0x2e, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x4c, 0x6f, 0x6f, 0x6b, 0x75, 0x70,
-        0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74,
-        0x65, 0x6d, 0x73, 0x20, 0x72, 0x6f, 0x6f, 0x6d, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x63,
-        0x68, 0x61, 0x72, 0x61, 0x63, 0x74, 0x65, 0x72, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
-        0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x2e, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2,
-        0x95, 0x91, 0x20, 0x33, 0x2e, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x6c, 0x6f, 0x6f, 0x6b, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x53, 0x68,
-        0x6f, 0x77, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x20, 0x61,
-        0x6e, 0x64, 0x20, 0x63, 0x68, 0x61, 0x72, 0x61, 0x63, 0x74, 0x65, 0x72, 0x73, 0x20, 0x69,
-        0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x72,
-        0x6f, 0x6f, 0x6d, 0x20, 0x61, 0x73, 0x20, 0x77, 0x65, 0x6c, 0x6c, 0x20, 0x61, 0x73, 0x20,
-        0x6e, 0x65, 0x61, 0x72, 0x62, 0x79, 0x20, 0x72, 0x6f, 0x6f, 0x6d, 0x73, 0x2e, 0x20, 0xe2,
-        0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x34, 0x2e, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x67,
-        0x6f, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95,
-        0x91, 0x20, 0x47, 0x6f, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65,
-        0x72, 0x65, 0x6e, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x6d, 0x2e, 0x20, 0x45, 0x6e, 0x74, 0x65,
-        0x72, 0x20, 0x67, 0x6f, 0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20, 0x62,
-        0x79, 0x20, 0x61, 0x20, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x35, 0x2e, 0x20, 0xe2,
-        0x95, 0x91, 0x20, 0x74, 0x61, 0x6b, 0x65, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x54, 0x61, 0x6b, 0x65, 0x20, 0x61, 0x6e, 0x20, 0x69,
-        0x74, 0x65, 0x6d, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x6f,
-        0x6f, 0x6d, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x79, 0x6f, 0x75, 0x72, 0x20, 0x49, 0x6e,
-        0x76, 0x65, 0x6e, 0x74, 0x6f, 0x72, 0x79, 0x2e, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20,
-        0x36, 0x2e, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x64, 0x72, 0x6f, 0x70, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x44, 0x72, 0x6f, 0x70, 0x20,
-        0x61, 0x6e, 0x20, 0x69, 0x74, 0x65, 0x6d, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x79, 0x6f,
-        0x75, 0x72, 0x20, 0x49, 0x6e, 0x76, 0x65, 0x6e, 0x74, 0x6f, 0x72, 0x79, 0x20, 0x74, 0x6f,
-        0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x6f, 0x6f, 0x6d, 0x2e, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a,
-        0xe2, 0x95, 0x91, 0x20, 0x37, 0x2e, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x69, 0x6e, 0x76, 0x65,
-        0x6e, 0x74, 0x6f, 0x72, 0x79, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x53,
-        0x68, 0x6f, 0x77, 0x20, 0x79, 0x6f, 0x75, 0x72, 0x20, 0x69, 0x6e, 0x76, 0x65, 0x6e, 0x74,
-        0x6f, 0x72, 0x79, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x38, 0x2e, 0x20, 0xe2, 0x95, 0x91, 0x20,
-        0x63, 0x6c, 0x75, 0x65, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2,
-        0x95, 0x91, 0x20, 0x4d, 0x61, 0x6b, 0x65, 0x20, 0x61, 0x20, 0x67, 0x75, 0x65, 0x73, 0x73,
-        0x2e, 0x20, 0x45, 0x6e, 0x74, 0x65, 0x72, 0x20, 0x63, 0x6c, 0x75, 0x65, 0x20, 0x66, 0x6f,
-        0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x63, 0x68, 0x61,
-        0x72, 0x61, 0x63, 0x74, 0x65, 0x72, 0x2e, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x20, 0x20, 0x20,
-        0xe2, 0x95, 0x91, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91,
-        0x20, 0x39, 0x2e, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x67, 0x2a, 0x6d, 0x65, 0x5f, 0x73, 0x2a,
-        0x6c, 0x2a, 0x74, 0x2a, 0x2a, 0x6e, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x46, 0x6f, 0x72, 0x20,
-        0x61, 0x6c, 0x6c, 0x20, 0x79, 0x6f, 0x75, 0x20, 0x57, 0x4f, 0x52, 0x44, 0x4c, 0x45, 0x20,
-        0x66, 0x61, 0x6e, 0x73, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x2d,
-        0x2d, 0x61, 0x6e, 0x20, 0x45, 0x41, 0x53, 0x54, 0x45, 0x52, 0x20, 0x45, 0x47, 0x47, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91,
-        0x0a, 0xe2, 0x95, 0x9a, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0xa9, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0xa9, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95,
-        0x9d, 0x0a, 0x00,
-    ];
-    help_command_array
+use adventure::{CommandInfo, MapView, Notes, COMMANDS};
+
+use crate::{
+    messages::{text, words},
+    render::time,
+    scores::HighScores,
+};
+
+/// Draws a box-drawn table. Headers are centered and cells are left-aligned;
+/// rows that are shorter than the header are padded with blank cells.
+fn table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let len = |text: &str| text.chars().count();
+    let widths: Vec<usize> = headers
+        .iter()
+        .enumerate()
+        .map(|(column, header)| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| len(cell))
+                .fold(len(header), usize::max)
+        })
+        .collect();
+
+    let rule = |left: &str, middle: &str, right: &str| {
+        let segments: Vec<String> = widths.iter().map(|width| "═".repeat(width + 2)).collect();
+        format!("{}{}{}\n", left, segments.join(middle), right)
+    };
+
+    let mut out = rule("╔", "╦", "╗");
+    out.push('║');
+    for (header, width) in headers.iter().zip(&widths) {
+        let padding = width - len(header);
+        let left = padding / 2;
+        out.push_str(&format!(
+            " {:left$}{}{:right$} ║",
+            "",
+            header,
+            "",
+            left = left,
+            right = padding - left
+        ));
+    }
+    out.push('\n');
+    out.push_str(&rule("╠", "╬", "╣"));
+    for row in rows {
+        out.push('║');
+        for (column, width) in widths.iter().enumerate() {
+            let cell = row.get(column).map(String::as_str).unwrap_or_default();
+            out.push_str(&format!(" {}{:pad$} ║", cell, "", pad = width - len(cell)));
+        }
+        out.push('\n');
+    }
+    out.push_str(&rule("╚", "╩", "╝"));
+    out
+}
+
+/// Secret commands are listed with their vowels hidden.
+fn hint(syntax: &str) -> String {
+    syntax
+        .chars()
+        .map(|c| if "aeiou".contains(c) { '*' } else { c })
+        .collect()
+}
+
+/// Commands handled by the terminal frontend rather than the engine.
+pub(crate) const FRONTEND_COMMANDS: &[&str] = &["alias", "scores"];
+
+/// The help row of a command: its syntax, shorter forms and description.
+fn command_row(name: &str) -> (String, String, String) {
+    (
+        text(&format!("syntax.{}", name)).to_owned(),
+        words(name)[1..].join(", "),
+        text(&format!("help.{}", name)).to_owned(),
+    )
+}
+
+/// The `help` table, built from the command registry.
+pub(crate) fn command_table() -> String {
+    let mut rows = Vec::new();
+    let (secret, public): (Vec<&CommandInfo>, Vec<&CommandInfo>) =
+        COMMANDS.iter().partition(|info| info.secret);
+    let public = public
+        .iter()
+        .map(|info| info.name)
+        .chain(FRONTEND_COMMANDS.iter().copied());
+    for name in public {
+        let (syntax, short, description) = command_row(name);
+        rows.push(vec![
+            format!("{}.", rows.len() + 1),
+            syntax,
+            short,
+            description,
+        ]);
+    }
+    // the shortest word for each way, e.g. `n, s, e, w`
+    let compass: Vec<&str> = ["north", "south", "east", "west"]
+        .iter()
+        .filter_map(|name| {
+            words(name)
+                .into_iter()
+                .min_by_key(|word| word.chars().count())
+        })
+        .collect();
+    rows.push(vec![
+        String::new(),
+        compass.join(", "),
+        String::new(),
+        text("help.compass").to_owned(),
+    ]);
+    if !secret.is_empty() {
+        rows.push(Vec::new());
+    }
+    let numbered = COMMANDS.len() - secret.len() + FRONTEND_COMMANDS.len();
+    for (n, info) in secret.iter().enumerate() {
+        let (syntax, _, description) = command_row(info.name);
+        rows.push(vec![
+            format!("{}.", numbered + n + 1),
+            hint(&syntax),
+            String::new(),
+            description,
+        ]);
+    }
+    let headers = ["help.number", "help.command", "help.short", "help.function"].map(text);
+    table(&headers, &rows)
+}
+
+/// The `list` table of everything in play, one column each.
+pub(crate) fn list_table(rooms: &[String], items: &[String], characters: &[String]) -> String {
+    let columns = [rooms, items, characters];
+    let height = columns.iter().map(|column| column.len()).max().unwrap_or(0);
+    let rows: Vec<Vec<String>> = (0..height)
+        .map(|i| {
+            columns
+                .iter()
+                .map(|column| column.get(i).cloned().unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    table(
+        &["list.rooms", "list.items", "list.characters"].map(text),
+        &rows,
+    )
+}
+
+/// The `scores` table, best first.
+pub(crate) fn scores_table(scores: &HighScores) -> String {
+    let rows: Vec<Vec<String>> = scores
+        .iter()
+        .enumerate()
+        .map(|(n, entry)| {
+            vec![
+                format!("{}.", n + 1),
+                entry.score.points.to_string(),
+                entry.score.clues.to_string(),
+                entry.score.moves.to_string(),
+                time(entry.score.seconds),
+                entry.scenario.clone(),
+            ]
+        })
+        .collect();
+    let headers = [
+        "scores.number",
+        "scores.points",
+        "scores.clues",
+        "scores.moves",
+        "scores.time",
+        "scores.case",
+    ]
+    .map(text);
+    table(&headers, &rows)
+}
+
+/// The `map` of the house, one box per cell of the board. Each room shows
+/// whether the player is there (`@`) or has been there (`+`), and the
+/// characters last seen in it.
+pub(crate) fn map_table(map: &MapView) -> String {
+    let len = |text: &str| text.chars().count();
+    let cells: Vec<Vec<String>> = map
+        .cells
+        .iter()
+        .map(|cell| match cell {
+            Some(room) => {
+                let mark = if room.here {
+                    '@'
+                } else if room.visited {
+                    '+'
+                } else {
+                    ' '
+                };
+                let characters = room.characters.iter().map(|name| format!("  {}", name));
+                std::iter::once(format!("{} {}", mark, room.name))
+                    .chain(characters)
+                    .collect()
+            }
+            None => Vec::new(),
+        })
+        .collect();
+    let widths: Vec<usize> = (0..map.width)
+        .map(|column| {
+            cells
+                .iter()
+                .skip(column)
+                .step_by(map.width)
+                .flatten()
+                .map(|line| len(line))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let rule = |left: &str, middle: &str, right: &str| {
+        let segments: Vec<String> = widths.iter().map(|width| "═".repeat(width + 2)).collect();
+        format!("{}{}{}\n", left, segments.join(middle), right)
+    };
+
+    let mut out = rule("╔", "╦", "╗");
+    for (n, row) in cells.chunks(map.width).enumerate() {
+        if n > 0 {
+            out.push_str(&rule("╠", "╬", "╣"));
+        }
+        let height = row.iter().map(Vec::len).max().unwrap_or(0).max(1);
+        for line in 0..height {
+            out.push('║');
+            for (cell, width) in row.iter().zip(&widths) {
+                let text = cell.get(line).map(String::as_str).unwrap_or_default();
+                out.push_str(&format!(" {}{:pad$} ║", text, "", pad = width - len(text)));
+            }
+            out.push('\n');
+        }
+    }
+    out.push_str(&rule("╚", "╩", "╝"));
+    out.push_str(text("map.legend"));
+    out
+}
+
+/// The `notes` sheet: the rooms, items and characters of the game, one column
+/// each, with what is known about every one of them.
+pub(crate) fn notes_table(notes: &Notes) -> String {
+    let columns = [&notes.rooms, &notes.items, &notes.characters];
+    let height = columns.iter().map(|column| column.len()).max().unwrap_or(0);
+    let rows: Vec<Vec<String>> = (0..height)
+        .map(|i| {
+            columns
+                .iter()
+                .map(|column| match column.get(i) {
+                    Some((name, Some(mark))) => format!("[{}] {}", mark, name),
+                    Some((name, None)) => format!("[ ] {}", name),
+                    None => String::new(),
+                })
+                .collect()
+        })
+        .collect();
+    let mut out = table(
+        &["list.rooms", "list.items", "list.characters"].map(text),
+        &rows,
+    );
+    out.push_str(text("notes.legend"));
+    out
 }
 
-pub fn get_list_array() -> Vec<u8> {
-    let help_list_array = vec![
-        0xe2, 0x95, 0x94, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0xa6, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0xa6, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x97, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x20, 0x20, 0x20, 0x20, 0x52, 0x4f, 0x4f,
-        0x4d, 0x53, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x20, 0x20, 0x20,
-        0x49, 0x54, 0x45, 0x4d, 0x53, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x43,
-        0x48, 0x41, 0x52, 0x41, 0x43, 0x54, 0x45, 0x52, 0x53, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2,
-        0x95, 0xa0, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0xac, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0xac, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2,
-        0x95, 0xa3, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x48, 0x61, 0x72, 0x72, 0x79, 0x27, 0x73, 0x20,
-        0x52, 0x6f, 0x6f, 0x6d, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x48, 0x61, 0x69, 0x72,
-        0x64, 0x72, 0x79, 0x65, 0x72, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x50, 0x65,
-        0x74, 0x65, 0x72, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95,
-        0x91, 0x20, 0x4c, 0x65, 0x78, 0x69, 0x27, 0x73, 0x20, 0x52, 0x6f, 0x6f, 0x6d, 0x20, 0x20,
-        0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x42, 0x65, 0x64, 0x73, 0x68, 0x65, 0x65, 0x74, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x4b, 0x61, 0x74, 0x69, 0x65, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x53, 0x61, 0x76,
-        0x61, 0x6e, 0x61, 0x68, 0x27, 0x73, 0x20, 0x52, 0x6f, 0x6f, 0x6d, 0x20, 0xe2, 0x95, 0x91,
-        0x20, 0x57, 0x69, 0x6e, 0x65, 0x20, 0x42, 0x6f, 0x74, 0x74, 0x6c, 0x65, 0x20, 0x20, 0xe2,
-        0x95, 0x91, 0x20, 0x4c, 0x65, 0x78, 0x69, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2,
-        0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x41, 0x74, 0x74, 0x69, 0x63, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x43, 0x68, 0x61, 0x69,
-        0x72, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x53, 0x61,
-        0x76, 0x61, 0x6e, 0x61, 0x68, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95,
-        0x91, 0x20, 0x48, 0x61, 0x6c, 0x6c, 0x77, 0x61, 0x79, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x4b, 0x6e, 0x69, 0x66, 0x65, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x48, 0x61, 0x72, 0x72, 0x79, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x42, 0x61, 0x63,
-        0x6b, 0x79, 0x61, 0x72, 0x64, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91,
-        0x20, 0x52, 0x75, 0x62, 0x62, 0x65, 0x72, 0x20, 0x44, 0x75, 0x63, 0x6b, 0x79, 0x20, 0xe2,
-        0x95, 0x91, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2,
-        0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x44, 0x72, 0x61, 0x77, 0x69, 0x6e, 0x67, 0x20,
-        0x52, 0x6f, 0x6f, 0x6d, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95,
-        0x91, 0x20, 0x50, 0x65, 0x74, 0x65, 0x72, 0x27, 0x73, 0x20, 0x52, 0x6f, 0x6f, 0x6d, 0x20,
-        0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-        0x20, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91, 0x0a, 0xe2, 0x95, 0x91, 0x20, 0x4b, 0x61, 0x74,
-        0x69, 0x65, 0x27, 0x73, 0x20, 0x52, 0x6f, 0x6f, 0x6d, 0x20, 0x20, 0x20, 0xe2, 0x95, 0x91,
-        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2,
-        0x95, 0x91, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0xe2,
-        0x95, 0x91, 0x0a, 0xe2, 0x95, 0x9a, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0xa9, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0xa9, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90, 0xe2, 0x95, 0x90,
-        0xe2, 0x95, 0x90, 0xe2, 0x95, 0x9d, 0x0a, 0x00,
-    ];
-    help_list_array
+#[cfg(test)]
+mod tests {
+    use adventure::MapRoom;
+
+    use super::*;
+
+    fn room(name: &str, here: bool, visited: bool, characters: &[&str]) -> Option<MapRoom> {
+        Some(MapRoom {
+            name: name.to_owned(),
+            here,
+            visited,
+            characters: characters.iter().map(|name| name.to_string()).collect(),
+        })
+    }
+
+    #[test]
+    fn the_map_draws_every_cell_with_what_is_known_of_it() {
+        let map = MapView {
+            width: 2,
+            height: 2,
+            cells: vec![
+                room("Hall", true, true, &["Katie"]),
+                room("Attic", false, true, &[]),
+                None,
+                room("Cellar", false, false, &["Harry", "Peter"]),
+            ],
+        };
+        let expected = "\
+╔═════════╦══════════╗
+║ @ Hall  ║ + Attic  ║
+║   Katie ║          ║
+╠═════════╬══════════╣
+║         ║   Cellar ║
+║         ║   Harry  ║
+║         ║   Peter  ║
+╚═════════╩══════════╝
+";
+        assert_eq!(
+            map_table(&map),
+            format!("{}{}", expected, text("map.legend"))
+        );
+    }
 }
@@ -0,0 +1,97 @@
+//! Undo and redo.
+//!
+//! Every command that changes the game records the [`Change`]s it made as a
+//! [`Step`]. Undoing a step reverts its changes in reverse order and redoing it
+//! plays them forward again, so the game ends up exactly as it was, down to
+//! the order of items in a room.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    command::Command,
+    game::Answer,
+    notes::{Mark, Sheet},
+    room::RoomId,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Change {
+    /// The player walked from one room to another. `index` is where they stood
+    /// in the character list of the room they left.
+    Moved {
+        from: RoomId,
+        to: RoomId,
+        index: usize,
+    },
+    /// A character was called to another room by a clue.
+    Summoned {
+        name: String,
+        from: RoomId,
+        to: RoomId,
+        index: usize,
+    },
+    /// An item went from the room to the end of the inventory.
+    Took {
+        name: String,
+        room: RoomId,
+        index: usize,
+    },
+    /// An item went from the inventory to the end of the room's item list.
+    Dropped {
+        name: String,
+        room: RoomId,
+        index: usize,
+    },
+    /// A clue was used up.
+    ClueUsed,
+    /// The answer to a clue changed what the notebook has deduced, from
+    /// `before` to `after`.
+    Deduced {
+        before: BTreeMap<(Sheet, String), Mark>,
+        after: BTreeMap<(Sheet, String), Mark>,
+    },
+    /// A clue was answered.
+    Answered(Answer),
+}
+
+/// The changes made by a single command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Step {
+    pub(crate) command: Command,
+    pub(crate) changes: Vec<Change>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct History {
+    done: Vec<Step>,
+    undone: Vec<Step>,
+}
+
+impl History {
+    /// Records a new step. Anything that was undone can no longer be redone.
+    pub(crate) fn record(&mut self, command: Command, changes: Vec<Change>) {
+        if changes.is_empty() {
+            return;
+        }
+        self.done.push(Step { command, changes });
+        self.undone.clear();
+    }
+
+    /// Forgets everything, so nothing before this point can be undone.
+    pub(crate) fn clear(&mut self) {
+        self.done.clear();
+        self.undone.clear();
+    }
+
+    pub(crate) fn undo(&mut self) -> Option<&Step> {
+        let step = self.done.pop()?;
+        self.undone.push(step);
+        self.undone.last()
+    }
+
+    pub(crate) fn redo(&mut self) -> Option<&Step> {
+        let step = self.undone.pop()?;
+        self.done.push(step);
+        self.done.last()
+    }
+}
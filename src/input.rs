@@ -0,0 +1,354 @@
+use adventure::{Difficulty, GameError};
+use rustyline::{error::ReadlineError, history::DefaultHistory, Editor};
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::{self, File},
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
+    process::exit,
+    str::FromStr,
+};
+
+use crate::completion::Completion;
+
+const TRANSCRIPT_HEADER: &str = "adventure-transcript: 1";
+
+/// Ends the header of a transcript; every line after it is player input.
+const TRANSCRIPT_SEPARATOR: &str = "---";
+
+/// How a game was set up: its seed, the scenario file it was played in (if
+/// not the built-in one), its difficulty (if not normal), its action points
+/// (if given on the command line), the language it was played in (if not
+/// English) and the aliases the player had defined.
+#[derive(Clone)]
+pub(crate) struct Setup {
+    pub(crate) seed: u64,
+    pub(crate) scenario: Option<PathBuf>,
+    pub(crate) difficulty: Option<Difficulty>,
+    pub(crate) actions: Option<usize>,
+    pub(crate) lang: Option<String>,
+    pub(crate) aliases: Vec<(String, String)>,
+}
+
+impl Setup {
+    /// The start of a transcript of a game set up this way, up to and
+    /// including the separator.
+    fn header(&self) -> String {
+        let mut header = format!("{}\nseed: {}\n", TRANSCRIPT_HEADER, self.seed);
+        if let Some(scenario) = &self.scenario {
+            header.push_str(&format!("scenario: {}\n", scenario.display()));
+        }
+        if let Some(difficulty) = self.difficulty {
+            header.push_str(&format!("difficulty: {}\n", difficulty));
+        }
+        if let Some(actions) = self.actions {
+            header.push_str(&format!("actions: {}\n", actions));
+        }
+        if let Some(lang) = &self.lang {
+            header.push_str(&format!("lang: {}\n", lang));
+        }
+        for (name, command) in &self.aliases {
+            header.push_str(&format!("alias: {} = {}\n", name, command));
+        }
+        header.push_str(TRANSCRIPT_SEPARATOR);
+        header.push('\n');
+        header
+    }
+}
+
+/// A recorded session: how the game was set up and every line the player
+/// typed.
+pub(crate) struct Transcript {
+    pub(crate) setup: Setup,
+    pub(crate) lines: Vec<String>,
+}
+
+impl Transcript {
+    pub(crate) fn read(path: &Path) -> Result<Self, String> {
+        fs::read_to_string(path)
+            .map_err(|error| error.to_string())?
+            .parse()
+    }
+}
+
+impl FromStr for Transcript {
+    type Err = String;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let mut lines = contents.lines();
+        if lines.next() != Some(TRANSCRIPT_HEADER) {
+            return Err("not an adventure transcript".to_owned());
+        }
+        let mut seed = None;
+        let mut scenario = None;
+        let mut difficulty = None;
+        let mut actions = None;
+        let mut lang = None;
+        let mut aliases = Vec::new();
+        loop {
+            match lines.next() {
+                Some(TRANSCRIPT_SEPARATOR) => break,
+                Some(line) => match line.split_once(": ") {
+                    Some(("seed", number)) => {
+                        seed = Some(
+                            number
+                                .parse()
+                                .map_err(|_| format!("`{}` is not a seed", number))?,
+                        )
+                    }
+                    Some(("scenario", path)) => scenario = Some(PathBuf::from(path)),
+                    Some(("difficulty", name)) => {
+                        difficulty =
+                            Some(name.parse().map_err(|error: GameError| error.to_string())?)
+                    }
+                    Some(("actions", points)) => {
+                        actions = Some(points.parse().map_err(|_| {
+                            format!("`{}` is not a number of action points", points)
+                        })?)
+                    }
+                    Some(("lang", code)) => lang = Some(code.to_owned()),
+                    Some(("alias", alias)) => {
+                        let (name, command) = alias
+                            .split_once(" = ")
+                            .ok_or_else(|| format!("bad alias `{}`", alias))?;
+                        aliases.push((name.to_owned(), command.to_owned()));
+                    }
+                    _ => return Err(format!("unexpected header line `{}`", line)),
+                },
+                None => return Err("transcript header is not terminated".to_owned()),
+            }
+        }
+        Ok(Transcript {
+            setup: Setup {
+                seed: seed.ok_or_else(|| "transcript has no seed".to_owned())?,
+                scenario,
+                difficulty,
+                actions,
+                lang,
+                aliases,
+            },
+            lines: lines.map(String::from).collect(),
+        })
+    }
+}
+
+/// Where the player's commands come from.
+///
+/// Lines from a replayed transcript are used first, then the script if one was
+/// given, otherwise stdin. When stdin is a terminal it is read through a line
+/// editor with history and tab completion. When recording, every line handed
+/// out is also appended to the transcript file.
+pub(crate) struct Input {
+    replay: VecDeque<String>,
+    /// Whether the last line handed out came from the replay.
+    replaying: bool,
+    /// The games saved by replayed lines, by name. A replay keeps them here
+    /// so that it neither overwrites the player's save files nor depends on
+    /// them.
+    replay_saves: HashMap<String, String>,
+    script: Option<VecDeque<String>>,
+    editor: Option<Editor<Completion, DefaultHistory>>,
+    record: Option<File>,
+}
+
+impl Input {
+    pub(crate) fn new(
+        replay: Option<Transcript>,
+        script: Option<&Path>,
+        record: Option<&Path>,
+        setup: &Setup,
+    ) -> io::Result<Self> {
+        let script = match script {
+            Some(path) => Some(
+                fs::read_to_string(path)?
+                    .lines()
+                    .map(String::from)
+                    .collect(),
+            ),
+            None => None,
+        };
+        let record = match record {
+            Some(path) => {
+                let mut file = File::create(path)?;
+                file.write_all(setup.header().as_bytes())?;
+                Some(file)
+            }
+            None => None,
+        };
+        let editor = if script.is_none() && io::stdin().is_terminal() {
+            Editor::new().ok()
+        } else {
+            None
+        };
+        Ok(Input {
+            replay: replay.map(|t| t.lines.into()).unwrap_or_default(),
+            replaying: false,
+            replay_saves: HashMap::new(),
+            script,
+            editor,
+            record,
+        })
+    }
+
+    /// Whether the player is typing at a terminal rather than feeding lines
+    /// in from a file or a pipe.
+    pub(crate) fn is_interactive(&self) -> bool {
+        self.editor.is_some()
+    }
+
+    /// Where `save` and `load` keep games while the line being run came from
+    /// a replayed transcript, or `None` when they should use files.
+    pub(crate) fn replay_saves(&mut self) -> Option<&mut HashMap<String, String>> {
+        self.replaying.then_some(&mut self.replay_saves)
+    }
+
+    /// Sets what tab completion offers for the next line typed.
+    pub(crate) fn complete_with(&mut self, completion: Completion) {
+        if let Some(editor) = self.editor.as_mut() {
+            editor.set_helper(Some(completion));
+        }
+    }
+
+    /// Returns the next line with surrounding whitespace removed, or `None`
+    /// once the script or stdin has run out.
+    pub(crate) fn get_user_input(&mut self) -> Option<String> {
+        self.replaying = !self.replay.is_empty();
+        let buffer = match self.replay.pop_front() {
+            Some(line) => line.trim().to_owned(),
+            None => match self.script.as_mut() {
+                Some(script) => script.pop_front()?.trim().to_owned(),
+                None => match self.editor.as_mut() {
+                    Some(editor) => read_editor(editor)?,
+                    None => read_stdin()?,
+                },
+            },
+        };
+        if let Some(file) = self.record.as_mut() {
+            if let Err(error) = writeln!(file, "{}", buffer) {
+                eprintln!("error: could not record input: {}", error);
+                self.record = None;
+            }
+        }
+        Some(buffer)
+    }
+}
+
+fn read_editor(editor: &mut Editor<Completion, DefaultHistory>) -> Option<String> {
+    match editor.readline("> ") {
+        Ok(line) => {
+            let line = line.trim().to_owned();
+            if !line.is_empty() {
+                // an in-memory history can't fail to take an entry
+                let _ = editor.add_history_entry(line.as_str());
+            }
+            Some(line)
+        }
+        Err(ReadlineError::Eof | ReadlineError::Interrupted) => None,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            exit(2);
+        }
+    }
+}
+
+fn read_stdin() -> Option<String> {
+    let mut buffer = String::new();
+    match io::stdin().read_line(&mut buffer) {
+        Ok(0) => None,
+        Ok(_) => Some(buffer.trim().to_owned()),
+        Err(error) => {
+            eprintln!("error: {}", error);
+            exit(2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> Setup {
+        Setup {
+            seed: 42,
+            scenario: None,
+            difficulty: None,
+            actions: None,
+            lang: None,
+            aliases: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn a_recorded_header_reads_back() {
+        let setup = Setup {
+            scenario: Some(PathBuf::from("cases/ship.scenario")),
+            difficulty: Some(Difficulty::Hard),
+            actions: Some(30),
+            lang: Some("fr".to_owned()),
+            ..setup()
+        };
+        let transcript: Transcript = format!("{}look\ngo north\n", setup.header())
+            .parse()
+            .unwrap();
+        let read = transcript.setup;
+        assert_eq!(read.seed, 42);
+        assert_eq!(read.scenario, setup.scenario);
+        assert_eq!(read.difficulty, Some(Difficulty::Hard));
+        assert_eq!(read.actions, Some(30));
+        assert_eq!(read.lang.as_deref(), Some("fr"));
+        assert_eq!(transcript.lines, ["look", "go north"]);
+    }
+
+    #[test]
+    fn the_aliases_it_was_played_with_read_back() {
+        let setup = Setup {
+            aliases: vec![
+                ("k".to_owned(), "clue Katie".to_owned()),
+                ("grab".to_owned(), "take".to_owned()),
+            ],
+            ..setup()
+        };
+        let header = setup.header();
+        assert!(header.contains("\nalias: k = clue Katie\nalias: grab = take\n---\n"));
+        let transcript: Transcript = format!("{}k\n", header).parse().unwrap();
+        assert_eq!(transcript.setup.aliases, setup.aliases);
+        assert_eq!(transcript.lines, ["k"]);
+        assert_eq!(
+            format!("{}\nseed: 1\nalias: k\n---\n", TRANSCRIPT_HEADER)
+                .parse::<Transcript>()
+                .err()
+                .unwrap(),
+            "bad alias `k`"
+        );
+    }
+
+    #[test]
+    fn only_the_defaults_are_left_out() {
+        assert_eq!(
+            setup().header(),
+            format!("{}\nseed: 42\n---\n", TRANSCRIPT_HEADER)
+        );
+    }
+
+    #[test]
+    fn bad_transcripts_are_rejected() {
+        let error = |text: &str| text.parse::<Transcript>().err().unwrap();
+        assert_eq!(error("look\n"), "not an adventure transcript");
+        assert_eq!(
+            error("adventure-transcript: 2\nseed: 1\n---\n"),
+            "not an adventure transcript"
+        );
+        assert_eq!(
+            error(&format!("{}\n---\n", TRANSCRIPT_HEADER)),
+            "transcript has no seed"
+        );
+        assert_eq!(
+            error(&format!("{}\nseed: 1\nlook\n", TRANSCRIPT_HEADER)),
+            "unexpected header line `look`"
+        );
+        assert_eq!(
+            error(&format!("{}\nseed: 1\n", TRANSCRIPT_HEADER)),
+            "transcript header is not terminated"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+//! The Clue game engine.
+//!
+//! [`Game`] holds the whole state of a match and is driven one [`Command`] at a
+//! time through [`Game::apply`], which returns the [`Event`]s a frontend should
+//! show. Nothing in this crate reads from stdin or writes to stdout.
+
+mod board;
+mod command;
+pub mod config;
+mod difficulty;
+mod entity;
+mod error;
+mod event;
+mod game;
+mod history;
+mod map;
+mod notes;
+mod room;
+mod save;
+mod scenario;
+mod score;
+
+pub use crate::board::{Board, Layout};
+pub use crate::command::{Command, CommandInfo, Direction, ParseError, Verb, COMMANDS};
+pub use crate::difficulty::{Difficulty, Feedback, Part};
+pub use crate::entity::{names_as_string, Entity, NameMatch};
+pub use crate::error::GameError;
+pub use crate::event::Event;
+pub use crate::game::{Answer, Game, GameStatus, Solution, State, WinningState};
+pub use crate::map::{MapRoom, MapView};
+pub use crate::notes::{Mark, Notes};
+pub use crate::room::{Room, RoomId, RoomView};
+pub use crate::save::{SaveError, SAVE_VERSION};
+pub use crate::scenario::{Scenario, ScenarioError, SCENARIO_VERSION};
+pub use crate::score::Score;
@@ -0,0 +1,182 @@
+//! What the player knows of the house, for the `map` command.
+//!
+//! The game remembers every room the player has stood in and where they last
+//! saw each character. Characters only move when called by a clue, so a
+//! sighting stays true until the player sees the room again without them.
+
+use crate::{game::Game, room::RoomId};
+
+/// The board as the player knows it, cell by cell and row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapView {
+    pub width: usize,
+    pub height: usize,
+    /// The room in each cell of the grid, or `None` for a gap.
+    pub cells: Vec<Option<MapRoom>>,
+}
+
+/// A room on the [`MapView`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapRoom {
+    pub name: String,
+    /// The player is in this room.
+    pub here: bool,
+    pub visited: bool,
+    /// The characters last seen in this room.
+    pub characters: Vec<String>,
+}
+
+impl Game {
+    pub fn map_view(&self) -> MapView {
+        let cells = self
+            .board
+            .cells
+            .iter()
+            .map(|cell| {
+                cell.map(|id| MapRoom {
+                    name: self.board.room(id).name.clone(),
+                    here: id == self.current_room,
+                    visited: self.visited.contains(&id),
+                    characters: self.last_seen_in(id),
+                })
+            })
+            .collect();
+        MapView {
+            width: self.board.width,
+            height: self.board.height,
+            cells,
+        }
+    }
+
+    /// Whether the player has been in `room`.
+    pub fn visited(&self, room: RoomId) -> bool {
+        self.visited.contains(&room)
+    }
+
+    /// The room where the player last saw `character`, if they have seen them.
+    pub fn last_seen(&self, character: &str) -> Option<RoomId> {
+        self.last_seen.get(character).copied()
+    }
+
+    /// The characters last seen in `room`, in the order of the character list.
+    fn last_seen_in(&self, room: RoomId) -> Vec<String> {
+        self.npcs
+            .iter()
+            .filter(|npc| self.last_seen(&npc.name) == Some(room))
+            .map(|npc| npc.name.clone())
+            .collect()
+    }
+
+    /// Takes note of the room the player is in and everyone in it.
+    pub(crate) fn observe(&mut self) {
+        let here = self.current_room;
+        self.visited.insert(here);
+        let present = &self.board.room(here).character_list;
+        for npc in &self.npcs {
+            if present.iter().any(|character| character.name == npc.name) {
+                self.last_seen.insert(npc.name.clone(), here);
+            } else if self.last_seen.get(&npc.name) == Some(&here) {
+                self.last_seen.remove(&npc.name);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        command::{Command, Direction},
+        entity::Entity,
+    };
+
+    /// A four-room game with Katie in the room the player starts in and Harry
+    /// somewhere else.
+    fn game() -> Game {
+        let mut game = Game::new(
+            vec!["Hall", "Attic", "Cellar", "Study"],
+            vec!["Knife", "Rope"],
+            vec!["Katie", "Harry"],
+            Entity::new("Detective".to_owned()),
+            7,
+        )
+        .unwrap();
+        let ids: Vec<RoomId> = game.board.rooms().map(|(id, _)| id).collect();
+        for id in &ids {
+            game.board
+                .room_mut(*id)
+                .character_list
+                .retain(|character| character.name == "Detective");
+        }
+        let here = game.current_room;
+        let elsewhere = ids.into_iter().find(|id| *id != here).unwrap();
+        for (room, name) in [(here, "Katie"), (elsewhere, "Harry")] {
+            game.board
+                .room_mut(room)
+                .character_list
+                .push(Entity::new(name.to_owned()));
+        }
+        game.observe();
+        game
+    }
+
+    fn room<'a>(map: &'a MapView, name: &str) -> &'a MapRoom {
+        map.cells
+            .iter()
+            .flatten()
+            .find(|room| room.name == name)
+            .expect("the room is on the map")
+    }
+
+    fn way_out(game: &Game) -> Direction {
+        [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ]
+        .into_iter()
+        .find(|direction| game.current_room().neighbour(direction).is_some())
+        .expect("every room in a grid has a way out")
+    }
+
+    #[test]
+    fn a_new_game_knows_only_the_room_the_player_is_in() {
+        let game = game();
+        let map = game.map_view();
+        assert_eq!(map.cells.len(), map.width * map.height);
+        let rooms: Vec<&MapRoom> = map.cells.iter().flatten().collect();
+        assert_eq!(rooms.len(), 4);
+        let here: Vec<&str> = rooms
+            .iter()
+            .filter(|room| room.here)
+            .map(|room| room.name.as_str())
+            .collect();
+        assert_eq!(here, [game.current_room().name.as_str()]);
+        assert_eq!(rooms.iter().filter(|room| room.visited).count(), 1);
+        assert_eq!(room(&map, here[0]).characters, ["Katie"]);
+    }
+
+    #[test]
+    fn a_sighting_lasts_until_the_room_is_seen_without_them() {
+        let mut game = game();
+        let start = game.current_room;
+        let name = game.current_room().name.clone();
+        let back = game.current_room().neighbour(&way_out(&game)).unwrap();
+        game.apply(Command::Go(way_out(&game))).unwrap();
+
+        // Katie leaves while the player is away, but the map can't know
+        game.board.room_mut(start).character_list.clear();
+        let map = game.map_view();
+        assert!(!room(&map, &name).here);
+        assert!(room(&map, &name).visited);
+        assert_eq!(room(&map, &name).characters, ["Katie"]);
+        assert_eq!(game.last_seen("Katie"), Some(start));
+
+        game.current_room = start;
+        game.observe();
+        assert!(game.visited(back));
+        assert!(room(&game.map_view(), &name).characters.is_empty());
+        assert_eq!(game.last_seen("Katie"), None);
+    }
+}
@@ -0,0 +1,273 @@
+//! The message catalog: every sentence the terminal game prints and every word
+//! the player types, looked up by key so the game can be played in another
+//! language.
+//!
+//! Catalogs are plain text with one `key: value` pair per line, like scenario
+//! files. `word.*` keys list the words that run a command or go a direction,
+//! the first one being the one shown. Anything a catalog leaves out falls back
+//! to English. The engine itself only understands the English words, so input
+//! is translated back to them before it is parsed.
+
+use std::{collections::HashMap, fmt, fs, io::ErrorKind, sync::OnceLock};
+
+use adventure::{Command, Direction, COMMANDS};
+
+use crate::help_menus::FRONTEND_COMMANDS;
+
+const HEADER: &str = "adventure-messages: 1";
+
+/// Catalogs that are built in, by the code given to `--lang`.
+const BUILT_IN: &[(&str, &str)] = &[
+    ("en", include_str!("../lang/en.messages")),
+    ("fr", include_str!("../lang/fr.messages")),
+];
+
+/// The English words for the compass directions, which are also their keys.
+const COMPASS: [(&str, Direction); 4] = [
+    ("north", Direction::North),
+    ("south", Direction::South),
+    ("east", Direction::East),
+    ("west", Direction::West),
+];
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+pub(crate) struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub(crate) fn english() -> Self {
+        let mut catalog = Catalog {
+            messages: HashMap::new(),
+        };
+        // the words of the engine's commands come from its command table
+        for info in COMMANDS {
+            let words: Vec<&str> = [info.name]
+                .into_iter()
+                .chain(info.short.iter().copied())
+                .collect();
+            catalog
+                .messages
+                .insert(format!("word.{}", info.name), words.join(", "));
+        }
+        catalog
+            .read(BUILT_IN[0].1, true)
+            .expect("the English catalog is valid");
+        catalog
+    }
+
+    /// Loads a built-in language by its code, e.g. `fr`, or else a catalog
+    /// file.
+    pub(crate) fn load(lang: &str) -> Result<Self, String> {
+        let contents = match BUILT_IN
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(lang))
+        {
+            Some((_, contents)) => contents.to_string(),
+            None => fs::read_to_string(lang).map_err(|error| match error.kind() {
+                ErrorKind::NotFound => {
+                    let codes: Vec<&str> = BUILT_IN.iter().map(|(code, _)| *code).collect();
+                    format!("not one of {} or a catalog file", codes.join(", "))
+                }
+                _ => error.to_string(),
+            })?,
+        };
+        let mut catalog = Catalog::english();
+        catalog.read(&contents, false)?;
+        catalog.check_words()?;
+        Ok(catalog)
+    }
+
+    /// Adds the messages in `contents`. Only the English catalog may add new
+    /// keys; anywhere else an unknown key is most likely a typo.
+    fn read(&mut self, contents: &str, english: bool) -> Result<(), String> {
+        let mut lines = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        if lines.next() != Some(HEADER) {
+            return Err("not an adventure message catalog".to_owned());
+        }
+        for line in lines {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("unexpected line `{}`", line))?;
+            let key = key.trim();
+            if !english && !self.messages.contains_key(key) {
+                return Err(format!("unknown message `{}`", key));
+            }
+            self.messages
+                .insert(key.to_owned(), value.trim().to_owned());
+        }
+        Ok(())
+    }
+
+    /// Makes sure every command and direction has a word and that no word
+    /// stands for two things.
+    fn check_words(&self) -> Result<(), String> {
+        let mut seen: HashMap<String, &str> = HashMap::new();
+        for key in self.messages.keys().filter(|key| key.starts_with("word.")) {
+            let words = split_words(&self.messages[key]);
+            if words.is_empty() {
+                return Err(format!("`{}` has no words", key));
+            }
+            for word in words {
+                if let Some(other) = seen.insert(word.to_lowercase(), key) {
+                    return Err(format!(
+                        "`{}` is used for both `{}` and `{}`",
+                        word, other, key
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> &str {
+        match self.messages.get(key) {
+            Some(message) => message,
+            None => panic!("no message `{}` in the English catalog", key),
+        }
+    }
+}
+
+fn split_words(list: &str) -> Vec<&str> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Makes `catalog` the one used for the rest of the game. Until this is
+/// called, everything is in English.
+pub(crate) fn use_catalog(catalog: Catalog) {
+    let _ = CATALOG.set(catalog);
+}
+
+fn catalog() -> &'static Catalog {
+    CATALOG.get_or_init(Catalog::english)
+}
+
+/// The message stored under `key`.
+pub(crate) fn text(key: &str) -> &'static str {
+    catalog().get(key)
+}
+
+/// The message stored under `key`, with `{0}`, `{1}`, ... replaced by `args`.
+pub(crate) fn format(key: &str, args: &[&dyn fmt::Display]) -> String {
+    let mut message = text(key).to_owned();
+    for (n, arg) in args.iter().enumerate() {
+        message = message.replace(&format!("{{{}}}", n), &arg.to_string());
+    }
+    message
+}
+
+/// Every word that runs the command or goes the direction called `name` in
+/// English, e.g. `take`, `north` or `alias`. The first one is the one shown.
+pub(crate) fn words(name: &str) -> Vec<&'static str> {
+    split_words(text(&format!("word.{}", name)))
+}
+
+/// The word shown for the command or direction called `name` in English.
+pub(crate) fn word(name: &str) -> &'static str {
+    words(name)[0]
+}
+
+/// Whether `word` runs a command or goes somewhere in this language.
+pub(crate) fn is_word(word: &str) -> bool {
+    command_name(word).is_some() || compass_name(word).is_some()
+}
+
+/// Whether the first word of `line` is `alias`; returns what follows it.
+pub(crate) fn alias_argument(line: &str) -> Option<&str> {
+    let (first, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    matches(first, "alias").then(|| rest.trim())
+}
+
+/// Whether `line` is the `scores` command.
+pub(crate) fn is_scores(line: &str) -> bool {
+    matches(line.trim(), "scores")
+}
+
+fn matches(typed: &str, name: &str) -> bool {
+    words(name)
+        .iter()
+        .any(|word| word.to_lowercase() == typed.to_lowercase())
+}
+
+fn command_name(typed: &str) -> Option<&'static str> {
+    COMMANDS
+        .iter()
+        .map(|info| info.name)
+        .chain(FRONTEND_COMMANDS.iter().copied())
+        .find(|name| matches(typed, name))
+}
+
+fn compass_name(typed: &str) -> Option<&'static str> {
+    COMPASS
+        .iter()
+        .map(|(name, _)| *name)
+        .find(|name| matches(typed, name))
+}
+
+/// Rewrites a line typed in this language into the English words the engine
+/// parses, e.g. `aller nord` into `go north`. Item and character names are
+/// left alone, as are words this language doesn't know.
+pub(crate) fn translate(line: &str) -> String {
+    let line = line.trim();
+    let (first, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let rest = rest.trim();
+    if rest.is_empty() {
+        if let Some(direction) = compass_name(first) {
+            return direction.to_owned();
+        }
+    }
+    match command_name(first) {
+        Some(name) if rest.is_empty() => name.to_owned(),
+        Some("go") => format!("go {}", translate_direction(rest)),
+        Some(name) => format!("{} {}", name, rest),
+        None => line.to_owned(),
+    }
+}
+
+/// The English word for a direction typed in this language.
+pub(crate) fn translate_direction(typed: &str) -> String {
+    match compass_name(typed.trim()) {
+        Some(name) => name.to_owned(),
+        None => typed.trim().to_owned(),
+    }
+}
+
+/// How a direction is shown in a room, e.g. `North`.
+pub(crate) fn direction(direction: &Direction) -> String {
+    match COMPASS.iter().find(|(_, compass)| compass == direction) {
+        Some((name, _)) => text(&format!("direction.{}", name)).to_owned(),
+        None => direction.to_string(),
+    }
+}
+
+/// How a direction is typed, e.g. `north`.
+pub(crate) fn direction_word(direction: &Direction) -> String {
+    match COMPASS.iter().find(|(_, compass)| compass == direction) {
+        Some((name, _)) => word(name).to_owned(),
+        None => direction.to_string().to_lowercase(),
+    }
+}
+
+/// A command the way it would be typed in this language, e.g. `prendre Knife`.
+pub(crate) fn command(command: &Command) -> String {
+    let verb = word(command.verb().info().name);
+    match command {
+        Command::Go(direction) => format!("{} {}", verb, direction_word(direction)),
+        Command::Take(argument)
+        | Command::Drop(argument)
+        | Command::Clue(argument)
+        | Command::Accuse(argument)
+        | Command::Save(argument)
+        | Command::Load(argument) => format!("{} {}", verb, argument),
+        Command::Mark(name, Some(mark)) => format!("{} {} {}", verb, name, mark),
+        Command::Mark(name, None) => format!("{} {}", verb, name),
+        _ => verb.to_owned(),
+    }
+}
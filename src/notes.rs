@@ -0,0 +1,342 @@
+//! The detective's notebook shown by `notes`.
+//!
+//! Like the sheet that comes with the board game it has a line for every
+//! room, item and character. Each answer to a clue crosses off whatever it
+//! rules out and ticks whatever it proves; the player can mark lines
+//! themselves with `mark`. The player's marks are kept apart from what the
+//! answers prove: they are shown instead of it, but nothing is ever deduced
+//! from a hunch. Taking back a clue with `undo` takes back what its answer
+//! proved.
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use crate::{
+    difficulty::{Feedback, Part},
+    entity::{names_of, Entity},
+    error::GameError,
+    event::Event,
+    game::{resolve, Game, State},
+};
+
+/// What the notebook says about a room, item or character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    /// Not part of the solution.
+    Cleared,
+    /// A hunch of the player's.
+    Suspect,
+    /// Certainly part of the solution.
+    Proven,
+}
+
+/// The three lists of the notebook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Sheet {
+    Room,
+    Item,
+    Character,
+}
+
+/// Every line of the notebook, in the order of [`Game::board`],
+/// [`Game::game_items`] and [`Game::npcs`]. Lines nothing is known about have
+/// no mark.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notes {
+    pub rooms: Vec<(String, Option<Mark>)>,
+    pub items: Vec<(String, Option<Mark>)>,
+    pub characters: Vec<(String, Option<Mark>)>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Notebook {
+    /// What the answers to clues have proven.
+    pub(crate) deduced: BTreeMap<(Sheet, String), Mark>,
+    /// The marks the player made with `mark`.
+    pub(crate) marks: BTreeMap<(Sheet, String), Mark>,
+}
+
+impl Notebook {
+    fn set(&mut self, sheet: Sheet, name: &str, mark: Mark) {
+        self.deduced.insert((sheet, name.to_owned()), mark);
+    }
+
+    /// What the answers have proven about a line.
+    fn get(&self, sheet: Sheet, name: &str) -> Option<Mark> {
+        self.deduced.get(&(sheet, name.to_owned())).copied()
+    }
+
+    /// The mark shown on a line: the player's own, or else what was proven.
+    fn shown(&self, sheet: Sheet, name: &str) -> Option<Mark> {
+        self.marks
+            .get(&(sheet, name.to_owned()))
+            .copied()
+            .or_else(|| self.get(sheet, name))
+    }
+
+    /// Notes down one part of an answer: a miss clears everything that was
+    /// there, and a match proves the one thing there that isn't cleared yet.
+    fn answer(&mut self, sheet: Sheet, present: &[String], state: State) {
+        match state {
+            State::Miss => {
+                for name in present {
+                    self.set(sheet, name, Mark::Cleared);
+                }
+            }
+            State::Match => {
+                let open: Vec<&String> = present
+                    .iter()
+                    .filter(|name| self.get(sheet, name) != Some(Mark::Cleared))
+                    .collect();
+                if let [name] = open[..] {
+                    self.set(sheet, name, Mark::Proven);
+                }
+            }
+        }
+    }
+
+    /// Once something is proven the rest of its list is cleared, and when
+    /// all but one are cleared the last one is proven.
+    fn deduce(&mut self, sheet: Sheet, names: &[String]) {
+        let open: Vec<&String> = names
+            .iter()
+            .filter(|name| self.get(sheet, name) != Some(Mark::Cleared))
+            .collect();
+        if let [name] = open[..] {
+            self.set(sheet, name, Mark::Proven);
+        }
+        if names
+            .iter()
+            .any(|name| self.get(sheet, name) == Some(Mark::Proven))
+        {
+            for name in names {
+                if self.get(sheet, name) != Some(Mark::Proven) {
+                    self.set(sheet, name, Mark::Cleared);
+                }
+            }
+        }
+    }
+}
+
+impl Game {
+    pub fn notes(&self) -> Notes {
+        let sheet = |sheet| {
+            self.names(sheet)
+                .into_iter()
+                .map(|name| {
+                    let mark = self.notebook.shown(sheet, &name);
+                    (name, mark)
+                })
+                .collect()
+        };
+        Notes {
+            rooms: sheet(Sheet::Room),
+            items: sheet(Sheet::Item),
+            characters: sheet(Sheet::Character),
+        }
+    }
+
+    fn names(&self, sheet: Sheet) -> Vec<String> {
+        match sheet {
+            Sheet::Room => self
+                .board
+                .rooms()
+                .map(|(_, room)| room.name.clone())
+                .collect(),
+            Sheet::Item => self.game_items(),
+            Sheet::Character => self.npcs(),
+        }
+    }
+
+    /// Marks the room, item or character called `name`, or rubs the player's
+    /// mark out, which shows what was proven about it again.
+    pub(crate) fn mark(&mut self, name: &str, mark: Option<Mark>) -> Result<Vec<Event>, GameError> {
+        let sheets = [Sheet::Room, Sheet::Item, Sheet::Character];
+        let lines: Vec<(Sheet, String)> = sheets
+            .into_iter()
+            .flat_map(|sheet| self.names(sheet).into_iter().map(move |name| (sheet, name)))
+            .collect();
+        let entities: Vec<Entity> = lines
+            .iter()
+            .map(|(_, name)| Entity::new(name.clone()))
+            .collect();
+        let (sheet, name) = lines[resolve(name, &entities, GameError::UnknownName)?].clone();
+        let key = (sheet, name.clone());
+        match mark {
+            Some(mark) => self.notebook.marks.insert(key, mark),
+            None => self.notebook.marks.remove(&key),
+        };
+        Ok(vec![Event::Marked { name, mark }])
+    }
+
+    /// Notes down what the answer to a clue in the current room proves.
+    /// `items` are the ones that were in the room or the inventory.
+    pub(crate) fn take_notes(&mut self, feedback: &Feedback, items: &[String]) {
+        let room = [self.current_room().name.clone()];
+        let characters: Vec<String> = names_of(&self.current_room().character_list)
+            .into_iter()
+            .filter(|name| self.npcs.iter().any(|npc| npc.name == *name))
+            .collect();
+        let present = |part| match part {
+            Part::Room => (Sheet::Room, &room[..]),
+            Part::Item => (Sheet::Item, items),
+            Part::Character => (Sheet::Character, &characters[..]),
+        };
+        let all = [Part::Room, Part::Item, Part::Character];
+        // a count only says something when none or all of them match
+        let answers: Vec<(Part, State)> = match *feedback {
+            Feedback::Each(result) => vec![
+                (Part::Room, result.room_match),
+                (Part::Item, result.item_match),
+                (Part::Character, result.character_match),
+            ],
+            Feedback::Count(0) => all.iter().map(|&part| (part, State::Miss)).collect(),
+            Feedback::Count(3) => all.iter().map(|&part| (part, State::Match)).collect(),
+            Feedback::Count(_) => Vec::new(),
+            Feedback::One(part, state) => vec![(part, state)],
+        };
+        for (part, state) in answers {
+            let (sheet, names) = present(part);
+            self.notebook.answer(sheet, names, state);
+        }
+        for sheet in [Sheet::Room, Sheet::Item, Sheet::Character] {
+            let names = self.names(sheet);
+            self.notebook.deduce(sheet, &names);
+        }
+    }
+}
+
+impl fmt::Display for Mark {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            Mark::Cleared => "x",
+            Mark::Suspect => "?",
+            Mark::Proven => "!",
+        };
+        write!(f, "{}", display)
+    }
+}
+
+/// Reads the way a mark is typed and shown: `x`, `?` or `!`.
+impl FromStr for Mark {
+    type Err = ();
+
+    fn from_str(buffer: &str) -> Result<Self, Self::Err> {
+        match buffer.to_ascii_lowercase().as_str() {
+            "x" => Ok(Mark::Cleared),
+            "?" => Ok(Mark::Suspect),
+            "!" => Ok(Mark::Proven),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Sheet {
+    pub(crate) fn as_save_str(self) -> &'static str {
+        match self {
+            Sheet::Room => "room",
+            Sheet::Item => "item",
+            Sheet::Character => "character",
+        }
+    }
+
+    pub(crate) fn from_save_str(value: &str) -> Option<Self> {
+        match value {
+            "room" => Some(Sheet::Room),
+            "item" => Some(Sheet::Item),
+            "character" => Some(Sheet::Character),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::Command;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn a_miss_clears_everything_that_was_there() {
+        let mut notebook = Notebook::default();
+        notebook.answer(Sheet::Item, &names(&["Knife", "Rope"]), State::Miss);
+        assert_eq!(notebook.get(Sheet::Item, "Knife"), Some(Mark::Cleared));
+        assert_eq!(notebook.get(Sheet::Item, "Rope"), Some(Mark::Cleared));
+    }
+
+    #[test]
+    fn a_match_proves_only_the_one_thing_left_open() {
+        let mut notebook = Notebook::default();
+        let present = names(&["Knife", "Rope"]);
+        notebook.answer(Sheet::Item, &present, State::Match);
+        assert_eq!(notebook.get(Sheet::Item, "Knife"), None);
+        notebook.set(Sheet::Item, "Rope", Mark::Cleared);
+        notebook.answer(Sheet::Item, &present, State::Match);
+        assert_eq!(notebook.get(Sheet::Item, "Knife"), Some(Mark::Proven));
+    }
+
+    #[test]
+    fn proving_one_clears_the_rest_and_the_last_open_one_is_proven() {
+        let all = names(&["Knife", "Rope", "Candle"]);
+        let mut notebook = Notebook::default();
+        notebook.set(Sheet::Item, "Rope", Mark::Proven);
+        notebook.deduce(Sheet::Item, &all);
+        assert_eq!(notebook.get(Sheet::Item, "Knife"), Some(Mark::Cleared));
+        assert_eq!(notebook.get(Sheet::Item, "Candle"), Some(Mark::Cleared));
+
+        let mut notebook = Notebook::default();
+        notebook.set(Sheet::Item, "Knife", Mark::Cleared);
+        notebook.set(Sheet::Item, "Candle", Mark::Cleared);
+        notebook.deduce(Sheet::Item, &all);
+        assert_eq!(notebook.get(Sheet::Item, "Rope"), Some(Mark::Proven));
+    }
+
+    fn game() -> Game {
+        Game::new(
+            vec!["Hall", "Attic"],
+            vec!["Knife", "Rope"],
+            vec!["Katie", "Harry"],
+            Entity::new("You".to_owned()),
+            5,
+        )
+        .unwrap()
+    }
+
+    fn shown(notes: &Notes, name: &str) -> Option<Mark> {
+        let mut lines = notes
+            .rooms
+            .iter()
+            .chain(&notes.items)
+            .chain(&notes.characters);
+        lines.find(|(line, _)| line == name).unwrap().1
+    }
+
+    #[test]
+    fn the_players_marks_are_shown_but_never_deduced_from() {
+        let mut game = game();
+        game.apply(Command::Mark("katie".to_owned(), Some(Mark::Proven)))
+            .unwrap();
+        game.take_notes(&Feedback::Count(1), &[]);
+        let notes = game.notes();
+        assert_eq!(shown(&notes, "Katie"), Some(Mark::Proven));
+        assert_eq!(shown(&notes, "Harry"), None);
+
+        game.apply(Command::Mark("katie".to_owned(), None)).unwrap();
+        assert_eq!(shown(&game.notes(), "Katie"), None);
+    }
+
+    #[test]
+    fn undoing_a_clue_takes_back_what_it_proved() {
+        let mut game = game();
+        game.set_clues_undoable(true);
+        game.apply(Command::Clue("katie".to_owned())).unwrap();
+        let deduced = game.notebook.deduced.clone();
+        assert!(!deduced.is_empty());
+        game.apply(Command::Undo).unwrap();
+        assert!(game.notebook.deduced.is_empty());
+        game.apply(Command::Redo).unwrap();
+        assert_eq!(game.notebook.deduced, deduced);
+    }
+}
@@ -0,0 +1,137 @@
+use std::{env, path::PathBuf, process::exit};
+
+use adventure::Difficulty;
+
+use crate::render::Style;
+
+const USAGE: &str = "usage: adventure [--scenario <file>] [--seed <number>] \
+                     [--difficulty easy|normal|hard|expert] [--actions <number>] [--lang <code|file>] \
+                     [--render plain|color|json] [--tui] [--record <file>] [--replay <file>] [--script <file>]";
+
+/// Command-line options for the terminal frontend.
+pub(crate) struct Options {
+    pub(crate) scenario: Option<PathBuf>,
+    pub(crate) seed: Option<u64>,
+    pub(crate) difficulty: Option<Difficulty>,
+    /// Action points to play with, instead of whatever the scenario says.
+    pub(crate) actions: Option<usize>,
+    /// A built-in language such as `fr`, or a message catalog file.
+    pub(crate) lang: Option<String>,
+    pub(crate) render: Style,
+    /// Play full-screen instead of line by line.
+    pub(crate) tui: bool,
+    pub(crate) record: Option<PathBuf>,
+    pub(crate) replay: Option<PathBuf>,
+    pub(crate) script: Option<PathBuf>,
+}
+
+impl Options {
+    pub(crate) fn from_args() -> Self {
+        let mut options = Options {
+            scenario: None,
+            seed: None,
+            difficulty: None,
+            actions: None,
+            lang: None,
+            render: Style::default(),
+            tui: false,
+            record: None,
+            replay: None,
+            script: None,
+        };
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--seed" => {
+                    let value = Self::value_for(&arg, args.next());
+                    match value.parse() {
+                        Ok(seed) => options.seed = Some(seed),
+                        Err(_) => Self::fail(&format!("invalid seed `{}`", value)),
+                    }
+                }
+                "--difficulty" => {
+                    let value = Self::value_for(&arg, args.next());
+                    match value.parse() {
+                        Ok(difficulty) => options.difficulty = Some(difficulty),
+                        Err(error) => Self::fail(&error.to_string()),
+                    }
+                }
+                "--actions" => {
+                    let value = Self::value_for(&arg, args.next());
+                    match value.parse() {
+                        Ok(0) | Err(_) => {
+                            Self::fail(&format!("invalid number of action points `{}`", value))
+                        }
+                        Ok(actions) => options.actions = Some(actions),
+                    }
+                }
+                "--scenario" => options.scenario = Some(Self::value_for(&arg, args.next()).into()),
+                "--lang" => options.lang = Some(Self::value_for(&arg, args.next())),
+                "--render" => {
+                    let value = Self::value_for(&arg, args.next());
+                    match value.parse() {
+                        Ok(style) => options.render = style,
+                        Err(error) => Self::fail(&error),
+                    }
+                }
+                "--tui" => options.tui = true,
+                "--record" => options.record = Some(Self::value_for(&arg, args.next()).into()),
+                "--replay" => options.replay = Some(Self::value_for(&arg, args.next()).into()),
+                "--script" => options.script = Some(Self::value_for(&arg, args.next()).into()),
+                "-h" | "--help" => {
+                    println!("{}", USAGE);
+                    exit(0);
+                }
+                _ => Self::fail(&format!("unknown argument `{}`", arg)),
+            }
+        }
+        if options.seed.is_some() && options.replay.is_some() {
+            Self::fail("`--seed` cannot be combined with `--replay`, which uses the recorded seed");
+        }
+        if options.scenario.is_some() && options.replay.is_some() {
+            Self::fail(
+                "`--scenario` cannot be combined with `--replay`, which uses the recorded scenario",
+            );
+        }
+        if options.difficulty.is_some() && options.replay.is_some() {
+            Self::fail(
+                "`--difficulty` cannot be combined with `--replay`, which uses the recorded difficulty",
+            );
+        }
+        if options.actions.is_some() && options.replay.is_some() {
+            Self::fail(
+                "`--actions` cannot be combined with `--replay`, which uses the recorded action points",
+            );
+        }
+        if options.lang.is_some() && options.replay.is_some() {
+            Self::fail(
+                "`--lang` cannot be combined with `--replay`, which uses the recorded language",
+            );
+        }
+        if options.tui {
+            let line_based = [
+                ("--render", options.render != Style::default()),
+                ("--record", options.record.is_some()),
+                ("--replay", options.replay.is_some()),
+                ("--script", options.script.is_some()),
+            ];
+            if let Some((flag, _)) = line_based.iter().find(|(_, given)| *given) {
+                Self::fail(&format!("`{}` cannot be combined with `--tui`", flag));
+            }
+        }
+        options
+    }
+
+    fn value_for(flag: &str, value: Option<String>) -> String {
+        match value {
+            Some(value) => value,
+            None => Self::fail(&format!("`{}` needs a value", flag)),
+        }
+    }
+
+    fn fail(message: &str) -> ! {
+        eprintln!("error: {}", message);
+        eprintln!("{}", USAGE);
+        exit(2);
+    }
+}
@@ -0,0 +1,369 @@
+use adventure::{
+    Event, Feedback, GameError, MapView, Mark, Part, RoomView, Scenario, Score, State, COMMANDS,
+};
+
+use super::Renderer;
+use crate::{
+    messages::{self, text, words},
+    scores::HighScores,
+};
+
+/// One JSON object per line for every event, error, message and prompt, for
+/// programs that play the game or show it in their own way.
+///
+/// Game data such as room and item names is given as is. Messages and
+/// prompts are in the language of the game.
+pub(crate) struct Json;
+
+/// A JSON string literal.
+fn string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn strings<S: AsRef<str>>(list: &[S]) -> String {
+    let items: Vec<String> = list.iter().map(|item| string(item.as_ref())).collect();
+    format!("[{}]", items.join(","))
+}
+
+fn optional(text: Option<&str>) -> String {
+    text.map(string).unwrap_or_else(|| "null".to_owned())
+}
+
+fn state(state: State) -> String {
+    string(match state {
+        State::Match => "match",
+        State::Miss => "miss",
+    })
+}
+
+/// What the difficulty lets the player know of an answer: `room_match`,
+/// `item_match` and `character_match`, only how many are `correct`, or the
+/// `part` that was picked and whether it is a `match`.
+fn feedback(feedback: &Feedback) -> Vec<(&'static str, String)> {
+    match feedback {
+        Feedback::Each(result) => vec![
+            ("room_match", state(result.room_match)),
+            ("item_match", state(result.item_match)),
+            ("character_match", state(result.character_match)),
+        ],
+        Feedback::Count(n) => vec![("correct", n.to_string())],
+        Feedback::One(part, result) => {
+            let part = match part {
+                Part::Room => "room",
+                Part::Item => "item",
+                Part::Character => "character",
+            };
+            vec![("part", string(part)), ("match", state(*result))]
+        }
+    }
+}
+
+fn mark(mark: Option<Mark>) -> String {
+    match mark {
+        Some(Mark::Cleared) => string("cleared"),
+        Some(Mark::Suspect) => string("suspect"),
+        Some(Mark::Proven) => string("proven"),
+        None => "null".to_owned(),
+    }
+}
+
+/// A list of the notebook, e.g. `[{"name":"Attic","mark":"cleared"}]`.
+fn sheet(lines: &[(String, Option<Mark>)]) -> String {
+    let lines: Vec<String> = lines
+        .iter()
+        .map(|(name, line)| format!("{{\"name\":{},\"mark\":{}}}", string(name), mark(*line)))
+        .collect();
+    format!("[{}]", lines.join(","))
+}
+
+/// Prints an object whose first field says what it is, e.g.
+/// `{"event":"item_taken","item":"Knife"}`. The values are already JSON.
+fn object(kind: &str, name: &str, fields: &[(&str, String)]) {
+    let mut members = vec![format!("{}:{}", string(kind), string(name))];
+    members.extend(
+        fields
+            .iter()
+            .map(|(key, value)| format!("{}:{}", string(key), value)),
+    );
+    println!("{{{}}}", members.join(","));
+}
+
+fn room(room: &RoomView) -> Vec<(&'static str, String)> {
+    let exits: Vec<String> = room
+        .exits
+        .iter()
+        .map(|(direction, name)| {
+            format!(
+                "{{\"direction\":{},\"room\":{}}}",
+                string(&direction.to_string().to_lowercase()),
+                string(name)
+            )
+        })
+        .collect();
+    vec![
+        ("name", string(&room.name)),
+        ("description", optional(room.description.as_deref())),
+        ("exits", format!("[{}]", exits.join(","))),
+        ("items", strings(&room.items)),
+        ("characters", strings(&room.characters)),
+    ]
+}
+
+/// The rooms of the map with their place on the grid; gaps are left out.
+fn map(map: &MapView) -> Vec<(&'static str, String)> {
+    let rooms: Vec<String> = map
+        .cells
+        .iter()
+        .enumerate()
+        .filter_map(|(i, cell)| {
+            let room = cell.as_ref()?;
+            Some(format!(
+                "{{\"name\":{},\"row\":{},\"column\":{},\"here\":{},\"visited\":{},\"characters\":{}}}",
+                string(&room.name),
+                i / map.width,
+                i % map.width,
+                room.here,
+                room.visited,
+                strings(&room.characters)
+            ))
+        })
+        .collect();
+    vec![
+        ("width", map.width.to_string()),
+        ("height", map.height.to_string()),
+        ("rooms", format!("[{}]", rooms.join(","))),
+    ]
+}
+
+fn help() -> String {
+    let commands: Vec<String> = COMMANDS
+        .iter()
+        .filter(|info| !info.secret)
+        .map(|info| {
+            let words = words(info.name);
+            format!(
+                "{{\"name\":{},\"short\":{},\"syntax\":{},\"description\":{}}}",
+                string(words[0]),
+                strings(&words[1..]),
+                string(text(&format!("syntax.{}", info.name))),
+                string(text(&format!("help.{}", info.name)))
+            )
+        })
+        .collect();
+    format!("[{}]", commands.join(","))
+}
+
+fn score_fields(score: &Score) -> Vec<(&'static str, String)> {
+    vec![
+        ("points", score.points.to_string()),
+        ("clues", score.clues.to_string()),
+        ("moves", score.moves.to_string()),
+        ("seconds", score.seconds.to_string()),
+    ]
+}
+
+/// The name an error is known by in JSON.
+fn error_name(error: &GameError) -> &'static str {
+    match error {
+        GameError::UnknownItem(_) => "unknown_item",
+        GameError::UnknownCharacter(_) => "unknown_character",
+        GameError::UnknownRoom(_) => "unknown_room",
+        GameError::UnknownName(_) => "unknown_name",
+        GameError::Ambiguous { .. } => "ambiguous",
+        GameError::NoExit(_) => "no_exit",
+        GameError::NotFound(_) => "not_found",
+        GameError::NothingToTake => "nothing_to_take",
+        GameError::EmptyInventory => "empty_inventory",
+        GameError::NothingToUndo => "nothing_to_undo",
+        GameError::NothingToRedo => "nothing_to_redo",
+        GameError::NoCluesLeft => "no_clues_left",
+        GameError::NotEnoughPoints { .. } => "not_enough_points",
+        GameError::BadAccusation(_) => "bad_accusation",
+        GameError::GameOver => "game_over",
+        GameError::Invalid(_) => "invalid",
+        GameError::Save(_) => "save",
+    }
+}
+
+impl Renderer for Json {
+    fn banner(&mut self, scenario: &Scenario, seed: u64, max_clues: usize) {
+        object(
+            "event",
+            "start",
+            &[
+                ("title", string(&scenario.title)),
+                ("description", optional(scenario.description.as_deref())),
+                ("seed", seed.to_string()),
+                ("max_clues", max_clues.to_string()),
+            ],
+        );
+    }
+
+    fn room(&mut self, view: &RoomView) {
+        object("event", "room", &room(view));
+    }
+
+    fn event(&mut self, event: &Event) {
+        match event {
+            Event::Help => object("event", "help", &[("commands", help())]),
+            Event::List {
+                rooms,
+                items,
+                characters,
+            } => object(
+                "event",
+                "list",
+                &[
+                    ("rooms", strings(rooms)),
+                    ("items", strings(items)),
+                    ("characters", strings(characters)),
+                ],
+            ),
+            Event::Room(view) => self.room(view),
+            Event::Map(view) => object("event", "map", &map(view)),
+            Event::Notes(notes) => object(
+                "event",
+                "notes",
+                &[
+                    ("rooms", sheet(&notes.rooms)),
+                    ("items", sheet(&notes.items)),
+                    ("characters", sheet(&notes.characters)),
+                ],
+            ),
+            Event::Marked { name, mark: line } => object(
+                "event",
+                "marked",
+                &[("name", string(name)), ("mark", mark(*line))],
+            ),
+            Event::ItemTaken(item) => object("event", "item_taken", &[("item", string(item))]),
+            Event::ItemDropped(item) => object("event", "item_dropped", &[("item", string(item))]),
+            Event::Inventory(items) => object("event", "inventory", &[("items", strings(items))]),
+            Event::Clue {
+                room,
+                character,
+                items,
+                feedback: answer,
+            } => {
+                let mut fields = vec![
+                    ("room", string(room)),
+                    ("character", string(character)),
+                    ("items", strings(items)),
+                ];
+                fields.extend(feedback(answer));
+                object("event", "clue", &fields)
+            }
+            Event::AttemptsRemaining(n) => object(
+                "event",
+                "attempts_remaining",
+                &[("attempts", n.to_string())],
+            ),
+            Event::Won => object("event", "won", &[]),
+            Event::Accused {
+                character,
+                item,
+                room,
+            } => object(
+                "event",
+                "accused",
+                &[
+                    ("character", string(character)),
+                    ("item", string(item)),
+                    ("room", string(room)),
+                ],
+            ),
+            Event::Lost => object("event", "lost", &[]),
+            Event::Exhausted => object("event", "exhausted", &[]),
+            Event::Solution {
+                room,
+                item,
+                character,
+            } => object(
+                "event",
+                "solution",
+                &[
+                    ("room", string(room)),
+                    ("item", string(item)),
+                    ("character", string(character)),
+                ],
+            ),
+            Event::Undone(command) => object(
+                "event",
+                "undone",
+                &[("command", string(&messages::command(command)))],
+            ),
+            Event::Redone(command) => object(
+                "event",
+                "redone",
+                &[("command", string(&messages::command(command)))],
+            ),
+            Event::Saved(name) => object("event", "saved", &[("name", string(name))]),
+            Event::Loaded(name) => object("event", "loaded", &[("name", string(name))]),
+        }
+    }
+
+    fn error(&mut self, error: &GameError) {
+        let mut fields = vec![("message", string(&error.to_string()))];
+        if let GameError::Ambiguous { name, candidates } = error {
+            fields.push(("name", string(name)));
+            fields.push(("candidates", strings(candidates)));
+        }
+        if let GameError::NotEnoughPoints { cost, left } = error {
+            fields.push(("cost", cost.to_string()));
+            fields.push(("left", left.to_string()));
+        }
+        object("error", error_name(error), &fields);
+    }
+
+    fn message(&mut self, text: &str) {
+        let text = text.trim();
+        if !text.is_empty() {
+            object("message", text, &[]);
+        }
+    }
+
+    fn prompt(&mut self, text: &str) {
+        object("prompt", text.trim(), &[]);
+    }
+
+    fn score(&mut self, score: &Score, rank: Option<usize>) {
+        let mut fields = score_fields(score);
+        fields.push((
+            "rank",
+            rank.map_or_else(|| "null".to_owned(), |rank| (rank + 1).to_string()),
+        ));
+        object("event", "score", &fields);
+    }
+
+    fn scores(&mut self, scores: &HighScores) {
+        let entries: Vec<String> = scores
+            .iter()
+            .map(|entry| {
+                let mut fields = score_fields(&entry.score);
+                fields.push(("scenario", string(&entry.scenario)));
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(key, value)| format!("{}:{}", string(key), value))
+                    .collect();
+                format!("{{{}}}", fields.join(","))
+            })
+            .collect();
+        object(
+            "event",
+            "scores",
+            &[("scores", format!("[{}]", entries.join(",")))],
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Everything the terminal game shows goes through a [`Renderer`], so the
+//! same game can be drawn as plain text, in color or as JSON for other
+//! programs to read.
+
+mod json;
+mod text;
+
+use std::str::FromStr;
+
+use adventure::{Event, Game, GameError, Mark, Part, RoomView, Scenario, Score};
+
+use crate::{
+    messages::{format, text},
+    scores::HighScores,
+};
+
+pub(crate) use json::Json;
+pub(crate) use text::Text;
+
+pub(crate) trait Renderer {
+    /// The welcome shown before the first room. `max_clues` is the game's
+    /// own allowance, which the difficulty may have changed from the
+    /// scenario's.
+    fn banner(&mut self, scenario: &Scenario, seed: u64, max_clues: usize);
+    fn room(&mut self, room: &RoomView);
+    fn event(&mut self, event: &Event);
+    fn error(&mut self, error: &GameError);
+    /// Anything else the game has to say, such as the list of aliases. A
+    /// blank message only spaces out the text.
+    fn message(&mut self, text: &str);
+    /// Asks the player for something; the answer is the next line read.
+    fn prompt(&mut self, text: &str);
+    /// The score of a game that was just won, and its place in the high
+    /// scores (counting from 0) if it made the table.
+    fn score(&mut self, score: &Score, rank: Option<usize>);
+    fn scores(&mut self, scores: &HighScores);
+}
+
+/// The renderers that can be picked with `--render`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum Style {
+    /// The box-drawn text the game has always used.
+    #[default]
+    Plain,
+    Color,
+    Json,
+}
+
+impl FromStr for Style {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "plain" => Ok(Style::Plain),
+            "color" => Ok(Style::Color),
+            "json" => Ok(Style::Json),
+            _ => Err(format!("unknown renderer `{}`", name)),
+        }
+    }
+}
+
+impl Style {
+    pub(crate) fn renderer(self) -> Box<dyn Renderer> {
+        match self {
+            Style::Plain => Box::new(Text::plain()),
+            Style::Color => Box::new(Text::colored()),
+            Style::Json => Box::new(Json),
+        }
+    }
+}
+
+/// What to tell the player when a command fails.
+pub(crate) fn error_message(error: &GameError) -> String {
+    match error {
+        GameError::NoExit(_) => text("error.no-exit").to_owned(),
+        GameError::NothingToTake => text("error.nothing-to-take").to_owned(),
+        GameError::EmptyInventory => text("inventory.empty").to_owned(),
+        GameError::UnknownItem(_) => text("error.unknown-item").to_owned(),
+        GameError::UnknownCharacter(_) => text("error.unknown-character").to_owned(),
+        GameError::UnknownName(_) => text("error.unknown-name").to_owned(),
+        GameError::NothingToUndo => text("error.nothing-to-undo").to_owned(),
+        GameError::NothingToRedo => text("error.nothing-to-redo").to_owned(),
+        GameError::NoCluesLeft => text("error.no-clues-left").to_owned(),
+        GameError::NotEnoughPoints { cost, left } => {
+            format("error.not-enough-points", &[cost, left])
+        }
+        GameError::BadAccusation(_) => text("error.accusation").to_owned(),
+        GameError::Save(error) => format("error.save", &[error]),
+        GameError::GameOver => text("event.game-over").to_owned(),
+        GameError::Ambiguous { name, candidates } => format!(
+            "{} {}",
+            format("prompt.choose", &[name]),
+            candidates.join(", ")
+        ),
+        error => format("error.other", &[error]),
+    }
+}
+
+/// The message that shows one part of an answer, e.g. `ROOM: {0}`.
+pub(crate) fn part_key(part: Part) -> &'static str {
+    match part {
+        Part::Room => "answer.room",
+        Part::Item => "answer.item",
+        Part::Character => "answer.character",
+    }
+}
+
+/// A length of time as minutes and seconds, e.g. `4:05`.
+pub(crate) fn time(seconds: u64) -> String {
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// The line that sums up the score of a won game.
+pub(crate) fn score_message(score: &Score) -> String {
+    format(
+        "scores.yours",
+        &[
+            &score.points,
+            &score.clues,
+            &score.moves,
+            &time(score.seconds),
+        ],
+    )
+}
+
+/// What the player is told after marking a line of the notebook.
+pub(crate) fn marked_message(name: &str, mark: Option<Mark>) -> String {
+    match mark {
+        Some(Mark::Cleared) => format("notes.cleared", &[&name]),
+        Some(Mark::Suspect) => format("notes.suspect", &[&name]),
+        Some(Mark::Proven) => format("notes.proven", &[&name]),
+        None => format("notes.unmarked", &[&name]),
+    }
+}
+
+/// Shows every event produced by the engine in order, or why the command
+/// failed. A move that failed shows the room again.
+pub(crate) fn render(out: &mut dyn Renderer, game: &Game, result: &Result<Vec<Event>, GameError>) {
+    match result {
+        Ok(events) => events.iter().for_each(|event| out.event(event)),
+        Err(error) => {
+            out.error(error);
+            if let GameError::NoExit(_) = error {
+                out.room(&game.room_view());
+            }
+        }
+    }
+}
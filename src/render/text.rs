@@ -0,0 +1,279 @@
+use adventure::{
+    config::PRINT_WIDTH, names_as_string, Event, Feedback, GameError, RoomView, Scenario, Score,
+    State,
+};
+
+use super::{error_message, marked_message, part_key, score_message, Renderer};
+use crate::{
+    help_menus as menu,
+    messages::{self, format, text},
+    scores::HighScores,
+};
+
+/// What a piece of text is, which decides its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tone {
+    Normal,
+    Rule,
+    Title,
+    Heading,
+    Prompt,
+    Good,
+    Bad,
+}
+
+impl Tone {
+    /// The ANSI SGR parameters of the colored theme.
+    fn code(self) -> Option<&'static str> {
+        match self {
+            Tone::Normal => None,
+            Tone::Rule => Some("2"),
+            Tone::Title => Some("1;36"),
+            Tone::Heading => Some("1"),
+            Tone::Prompt => Some("33"),
+            Tone::Good => Some("32"),
+            Tone::Bad => Some("31"),
+        }
+    }
+}
+
+/// The game as lines of text framed by box-drawing rules, optionally in
+/// color.
+pub(crate) struct Text {
+    colored: bool,
+}
+
+impl Text {
+    pub(crate) fn plain() -> Self {
+        Text { colored: false }
+    }
+
+    pub(crate) fn colored() -> Self {
+        Text { colored: true }
+    }
+
+    fn paint(&self, tone: Tone, text: &str) -> String {
+        match tone.code() {
+            Some(code) if self.colored && !text.is_empty() => {
+                format!("\x1b[{}m{}\x1b[0m", code, text)
+            }
+            _ => text.to_owned(),
+        }
+    }
+
+    fn line(&self, tone: Tone, text: &str) {
+        println!("{}", self.paint(tone, text));
+    }
+
+    fn rule(&self) {
+        self.line(Tone::Rule, &"═".repeat(PRINT_WIDTH));
+    }
+
+    fn center(&self, tone: Tone, text: &str) {
+        let padlen = PRINT_WIDTH.saturating_sub(text.chars().count()) / 2;
+        println!("{:indent$}{}", "", self.paint(tone, text), indent = padlen);
+    }
+
+    fn state(&self, state: State) -> String {
+        match state {
+            State::Match => self.paint(Tone::Good, text("answer.match")),
+            State::Miss => self.paint(Tone::Bad, text("answer.miss")),
+        }
+    }
+
+    fn clue(&self, feedback: &Feedback) -> String {
+        match feedback {
+            Feedback::Each(win_state) => answer(
+                &self.state(win_state.room_match),
+                &self.state(win_state.item_match),
+                &self.state(win_state.character_match),
+            ),
+            Feedback::Count(n) => {
+                format!(
+                    "{}\n{} \n",
+                    text("answer.title"),
+                    format("answer.count", &[n])
+                )
+            }
+            Feedback::One(part, state) => format!(
+                "{}\n{} \n",
+                text("answer.title"),
+                format(part_key(*part), &[&self.state(*state)])
+            ),
+        }
+    }
+}
+
+/// The neighbouring rooms, each with the way to get there.
+fn around(room: &RoomView) -> String {
+    room.exits
+        .iter()
+        .map(|(direction, name)| format!(" {} ({}) |", name, messages::direction(direction)))
+        .collect()
+}
+
+/// The room, item and character of an answer, one per line.
+fn answer(room: &str, item: &str, character: &str) -> String {
+    format!(
+        "{}\n{} \n{} \n{} \n",
+        text("answer.title"),
+        format("answer.room", &[&room]),
+        format("answer.item", &[&item]),
+        format("answer.character", &[&character])
+    )
+}
+
+impl Renderer for Text {
+    fn banner(&mut self, scenario: &Scenario, seed: u64, _max_clues: usize) {
+        println!("\n");
+        self.center(Tone::Title, text("banner.welcome"));
+        self.center(
+            Tone::Normal,
+            &format("banner.title", &[&scenario.title, &seed]),
+        );
+        if let Some(description) = &scenario.description {
+            println!();
+            self.center(Tone::Normal, description);
+        }
+        println!();
+        self.center(Tone::Normal, text("banner.location"));
+        println!();
+    }
+
+    fn room(&mut self, room: &RoomView) {
+        self.rule();
+        self.center(Tone::Title, &room.name);
+        self.rule();
+        if let Some(description) = &room.description {
+            self.center(Tone::Normal, description);
+            self.rule();
+        }
+        self.center(Tone::Heading, text("room.around"));
+        self.center(Tone::Normal, &around(room));
+        self.rule();
+        self.center(Tone::Heading, &format("room.items", &[&room.name]));
+        self.center(
+            Tone::Normal,
+            &names_as_string(&room.items, text("room.no-items")),
+        );
+        self.rule();
+        self.center(Tone::Heading, &format("room.characters", &[&room.name]));
+        self.center(
+            Tone::Normal,
+            &names_as_string(&room.characters, text("room.no-characters")),
+        );
+        self.rule();
+    }
+
+    fn event(&mut self, event: &Event) {
+        match event {
+            Event::Help => println!("{}", menu::command_table()),
+            Event::List {
+                rooms,
+                items,
+                characters,
+            } => println!("{}", menu::list_table(rooms, items, characters)),
+            Event::Room(room) => self.room(room),
+            Event::Map(map) => println!("{}\n", menu::map_table(map)),
+            Event::Notes(notes) => println!("{}\n", menu::notes_table(notes)),
+            Event::Marked { name, mark } => self.line(Tone::Good, &marked_message(name, *mark)),
+            Event::ItemTaken(_) => self.line(Tone::Good, text("event.taken")),
+            Event::ItemDropped(_) => self.line(Tone::Good, text("event.dropped")),
+            Event::Inventory(items) => println!(
+                "{}",
+                format(
+                    "inventory.contents",
+                    &[&names_as_string(items, text("inventory.empty"))]
+                )
+            ),
+            Event::Clue { feedback, .. } => println!("\n{}", self.clue(feedback)),
+            Event::AttemptsRemaining(n) => println!("{}\n", format("event.attempts", &[n])),
+            Event::Won => {
+                println!("\n{}\n", self.paint(Tone::Good, text("event.won")));
+                self.line(Tone::Heading, text("event.game-over"));
+            }
+            Event::Accused {
+                character,
+                item,
+                room,
+            } => println!("\n{}\n", format("event.accused", &[character, item, room])),
+            Event::Lost => {
+                self.line(Tone::Bad, text("event.lost"));
+                self.line(Tone::Heading, text("event.game-over"));
+            }
+            Event::Exhausted => {
+                self.line(Tone::Bad, text("event.exhausted"));
+                self.line(Tone::Heading, text("event.game-over"));
+            }
+            Event::Solution {
+                room,
+                item,
+                character,
+            } => println!("{}", answer(room, item, character)),
+            Event::Undone(command) => self.line(
+                Tone::Good,
+                &format("event.undone", &[&messages::command(command)]),
+            ),
+            Event::Redone(command) => self.line(
+                Tone::Good,
+                &format("event.redone", &[&messages::command(command)]),
+            ),
+            Event::Saved(name) => {
+                println!(
+                    "{}\n",
+                    self.paint(Tone::Good, &format("event.saved", &[name]))
+                )
+            }
+            Event::Loaded(name) => {
+                println!(
+                    "{}\n",
+                    self.paint(Tone::Good, &format("event.loaded", &[name]))
+                )
+            }
+        }
+    }
+
+    fn error(&mut self, error: &GameError) {
+        let message = match error {
+            GameError::NoExit(_) => format!("\n{}\n", error_message(error)),
+            GameError::Save(_) | GameError::Ambiguous { .. } => {
+                format!("{}\n", error_message(error))
+            }
+            GameError::NothingToTake
+            | GameError::EmptyInventory
+            | GameError::UnknownItem(_)
+            | GameError::UnknownCharacter(_)
+            | GameError::UnknownName(_)
+            | GameError::NothingToUndo
+            | GameError::NothingToRedo
+            | GameError::NoCluesLeft
+            | GameError::NotEnoughPoints { .. }
+            | GameError::GameOver => error_message(error),
+            _ => format!("{}\n", error_message(error)),
+        };
+        self.line(Tone::Bad, &message);
+    }
+
+    fn message(&mut self, text: &str) {
+        self.line(Tone::Normal, text);
+    }
+
+    fn prompt(&mut self, text: &str) {
+        self.line(Tone::Prompt, text);
+    }
+
+    fn score(&mut self, score: &Score, rank: Option<usize>) {
+        println!();
+        self.line(Tone::Good, &score_message(score));
+        if let Some(rank) = rank {
+            self.line(Tone::Good, &format("scores.rank", &[&(rank + 1)]));
+        }
+    }
+
+    fn scores(&mut self, scores: &HighScores) {
+        match scores.iter().next() {
+            Some(_) => println!("\n{}\n", menu::scores_table(scores)),
+            None => println!("\n{}\n", text("scores.none")),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    board::Board,
+    command::Direction,
+    entity::{names_as_string, names_of, Entity},
+};
+
+/// Index of a room in its [`Board`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RoomId(pub(crate) usize);
+
+impl RoomId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Room {
+    pub(crate) name: String,
+    pub(crate) description: Option<String>,
+    /// Every way out of the room, in the order they are shown to the player.
+    pub(crate) exits: Vec<(Direction, RoomId)>,
+    pub(crate) item_list: Vec<Entity>,
+    pub(crate) character_list: Vec<Entity>,
+}
+
+impl Room {
+    pub(crate) fn new(name: String) -> Self {
+        Self {
+            name,
+            description: None,
+            exits: Vec::new(),
+            item_list: Vec::new(),
+            character_list: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn items(&self) -> &[Entity] {
+        &self.item_list
+    }
+
+    pub fn characters(&self) -> &[Entity] {
+        &self.character_list
+    }
+
+    pub fn exits(&self) -> &[(Direction, RoomId)] {
+        &self.exits
+    }
+
+    pub fn neighbour(&self, direction: &Direction) -> Option<RoomId> {
+        self.exits
+            .iter()
+            .find(|(exit, _)| exit == direction)
+            .map(|&(_, id)| id)
+    }
+
+    /// Adds an exit, replacing any existing exit in the same direction.
+    pub(crate) fn set_exit(&mut self, direction: Direction, to: RoomId) {
+        match self.exits.iter_mut().find(|(exit, _)| *exit == direction) {
+            Some(exit) => exit.1 = to,
+            None => self.exits.push((direction, to)),
+        }
+    }
+
+    pub fn around(&self, board: &Board) -> String {
+        self.view(board).around()
+    }
+    pub fn item_list_as_string(&self) -> String {
+        Entity::entity_list_as_string(&self.item_list, "No items.")
+    }
+    pub fn character_list_as_string(&self) -> String {
+        Entity::entity_list_as_string(&self.character_list, "No characters.")
+    }
+
+    /// Takes a snapshot of the room that can be handed to a frontend.
+    pub fn view(&self, board: &Board) -> RoomView {
+        let exits = self
+            .exits
+            .iter()
+            .map(|(direction, id)| (direction.clone(), board.room(*id).name.clone()))
+            .collect();
+        RoomView {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            exits,
+            items: names_of(&self.item_list),
+            characters: names_of(&self.character_list),
+        }
+    }
+}
+
+/// What the player can see from inside a room.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoomView {
+    pub name: String,
+    pub description: Option<String>,
+    pub exits: Vec<(Direction, String)>,
+    pub items: Vec<String>,
+    pub characters: Vec<String>,
+}
+
+impl RoomView {
+    pub fn around(&self) -> String {
+        let mut around: String = String::new();
+        fn form(str1: &str, str2: &str) -> String {
+            format!(" {} ({}) |", str1, str2)
+        }
+        for (direction, name) in &self.exits {
+            around.push_str(&form(name, &direction.to_string()));
+        }
+        around
+    }
+    pub fn item_list_as_string(&self) -> String {
+        names_as_string(&self.items, "No items.")
+    }
+    pub fn character_list_as_string(&self) -> String {
+        names_as_string(&self.characters, "No characters.")
+    }
+}
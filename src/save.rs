@@ -0,0 +1,769 @@
+//! Reading and writing saved games.
+//!
+//! A save file is plain text with one `key: value` pair per line, starting
+//! with the format version. The cells of the board are listed row by row as
+//! either `room:` or `empty:`, and every `description:`, `exit:`, `item:` and
+//! `character:` line belongs to the `room:` line above it; the `avatar:` line
+//! says which of those characters is the player, who must be in the
+//! `current-room:`. What the player knows of the house follows as `visited:`
+//! rooms and `seen:` lines such as `seen: Katie -> Attic`, and the notebook as `deduced:` lines for what the
+//! answers proved and `note:` lines for the player's own marks, each giving
+//! the list, the mark and the name, e.g. `note: item x Knife`. Each clue
+//! asked is an `answer:` line such as `answer: Katie -> Attic`, followed by an
+//! `answer-item:` line for every item that was at hand and an
+//! `answer-feedback:` line for what it told, e.g. `each match miss miss` for
+//! the room, the item and the character, `count 1` or `one item miss`. Only games
+//! played with action points have an `action-points:` line. The undo history
+//! is not saved, so a loaded game starts with nothing to undo.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error, fmt, io,
+    time::Duration,
+};
+
+use crate::{
+    board::Board,
+    command::Direction,
+    difficulty::{Feedback, Part},
+    entity::Entity,
+    game::{Answer, Game, GameStatus, Solution, State, WinningState},
+    history::History,
+    notes::{Mark, Notebook, Sheet},
+    room::Room,
+};
+
+/// Version written to new save files. Bump it whenever the layout changes.
+pub const SAVE_VERSION: u32 = 1;
+
+const HEADER: &str = "adventure-save";
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),
+    UnsupportedVersion(String),
+    Malformed(String),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io(error) => write!(f, "{}", error),
+            SaveError::UnsupportedVersion(version) => {
+                write!(f, "unsupported save version `{}`", version)
+            }
+            SaveError::Malformed(message) => write!(f, "malformed save file: {}", message),
+        }
+    }
+}
+
+impl error::Error for SaveError {}
+
+impl From<io::Error> for SaveError {
+    fn from(error: io::Error) -> Self {
+        SaveError::Io(error)
+    }
+}
+
+fn malformed(message: impl Into<String>) -> SaveError {
+    SaveError::Malformed(message.into())
+}
+
+impl GameStatus {
+    fn as_save_str(self) -> &'static str {
+        match self {
+            GameStatus::InProgress => "in-progress",
+            GameStatus::Won => "won",
+            GameStatus::Lost => "lost",
+        }
+    }
+
+    fn from_save_str(value: &str) -> Result<Self, SaveError> {
+        match value {
+            "in-progress" => Ok(GameStatus::InProgress),
+            "won" => Ok(GameStatus::Won),
+            "lost" => Ok(GameStatus::Lost),
+            _ => Err(malformed(format!("unknown status `{}`", value))),
+        }
+    }
+}
+
+impl State {
+    fn as_save_str(self) -> &'static str {
+        match self {
+            State::Match => "match",
+            State::Miss => "miss",
+        }
+    }
+
+    fn from_save_str(value: &str) -> Option<Self> {
+        match value {
+            "match" => Some(State::Match),
+            "miss" => Some(State::Miss),
+            _ => None,
+        }
+    }
+}
+
+impl Part {
+    fn as_save_str(self) -> &'static str {
+        match self {
+            Part::Room => "room",
+            Part::Item => "item",
+            Part::Character => "character",
+        }
+    }
+
+    fn from_save_str(value: &str) -> Option<Self> {
+        match value {
+            "room" => Some(Part::Room),
+            "item" => Some(Part::Item),
+            "character" => Some(Part::Character),
+            _ => None,
+        }
+    }
+}
+
+impl Feedback {
+    fn to_save_string(self) -> String {
+        match self {
+            Feedback::Each(state) => format!(
+                "each {} {} {}",
+                state.room_match.as_save_str(),
+                state.item_match.as_save_str(),
+                state.character_match.as_save_str()
+            ),
+            Feedback::Count(n) => format!("count {}", n),
+            Feedback::One(part, state) => {
+                format!("one {} {}", part.as_save_str(), state.as_save_str())
+            }
+        }
+    }
+
+    fn from_save_str(value: &str) -> Option<Self> {
+        let words: Vec<&str> = value.split_whitespace().collect();
+        match words[..] {
+            ["each", room, item, character] => Some(Feedback::Each(WinningState {
+                room_match: State::from_save_str(room)?,
+                item_match: State::from_save_str(item)?,
+                character_match: State::from_save_str(character)?,
+            })),
+            ["count", n] => n.parse().ok().filter(|n| *n <= 3).map(Feedback::Count),
+            ["one", part, matched] => Some(Feedback::One(
+                Part::from_save_str(part)?,
+                State::from_save_str(matched)?,
+            )),
+            _ => None,
+        }
+    }
+}
+
+struct SavedRoom {
+    name: String,
+    description: Option<String>,
+    exits: Vec<(Direction, String)>,
+    items: Vec<String>,
+    characters: Vec<String>,
+}
+
+struct SavedAnswer {
+    room: String,
+    character: String,
+    items: Vec<String>,
+    feedback: Option<Feedback>,
+}
+
+fn last_answer<'a>(
+    answers: &'a mut [SavedAnswer],
+    key: &str,
+) -> Result<&'a mut SavedAnswer, SaveError> {
+    answers
+        .last_mut()
+        .ok_or_else(|| malformed(format!("`{}` outside of an answer", key)))
+}
+
+fn last_room<'a>(
+    cells: &'a mut [Option<SavedRoom>],
+    key: &str,
+) -> Result<&'a mut SavedRoom, SaveError> {
+    cells
+        .last_mut()
+        .and_then(Option::as_mut)
+        .ok_or_else(|| malformed(format!("`{}` outside of a room", key)))
+}
+
+/// Reads a line of the notebook such as `item x Knife`.
+fn note(value: &str) -> Result<((Sheet, String), Mark), SaveError> {
+    let bad_note = || malformed(format!("bad note `{}`", value));
+    let mut parts = value.splitn(3, ' ');
+    let sheet = parts
+        .next()
+        .and_then(Sheet::from_save_str)
+        .ok_or_else(bad_note)?;
+    let mark = parts
+        .next()
+        .and_then(|mark| mark.parse().ok())
+        .ok_or_else(bad_note)?;
+    let name = parts.next().ok_or_else(bad_note)?;
+    Ok(((sheet, name.trim().to_owned()), mark))
+}
+
+fn yes_or_no(value: &str) -> Result<bool, SaveError> {
+    match value {
+        "yes" => Ok(true),
+        "no" => Ok(false),
+        _ => Err(malformed(format!("expected yes or no, found `{}`", value))),
+    }
+}
+
+impl Game {
+    pub fn to_save_string(&self) -> String {
+        let mut out = String::new();
+        let mut line = |key: &str, value: &str| {
+            out.push_str(key);
+            out.push(':');
+            if !value.is_empty() {
+                out.push(' ');
+                out.push_str(value);
+            }
+            out.push('\n');
+        };
+        line(HEADER, &SAVE_VERSION.to_string());
+        line("seed", &self.seed.to_string());
+        line("clues", &self.n_clue.to_string());
+        line("max-clues", &self.max_clues.to_string());
+        line("clues-asked", &self.clues_asked.to_string());
+        line("moves", &self.moves.to_string());
+        line("revealed", if self.revealed { "yes" } else { "no" });
+        line("seconds", &self.time.as_secs().to_string());
+        line("status", self.status.as_save_str());
+        line("difficulty", &self.difficulty.to_string());
+        if let Some(points) = self.action_points {
+            line("action-points", &points.to_string());
+        }
+        line(
+            "undoable-clues",
+            if self.clues_undoable { "yes" } else { "no" },
+        );
+        for npc in &self.npcs {
+            line("npc", &npc.name);
+        }
+        for item in &self.game_items {
+            line("game-item", &item.name);
+        }
+        line("width", &self.board.width.to_string());
+        line("height", &self.board.height.to_string());
+        for cell in &self.board.cells {
+            let Some(id) = *cell else {
+                line("empty", "");
+                continue;
+            };
+            let room = self.board.room(id);
+            line("room", &room.name);
+            if let Some(description) = &room.description {
+                line("description", description);
+            }
+            for (direction, to) in &room.exits {
+                line(
+                    "exit",
+                    &format!("{} -> {}", direction, self.board.room(*to).name),
+                );
+            }
+            for item in &room.item_list {
+                line("item", &item.name);
+            }
+            for character in &room.character_list {
+                line("character", &character.name);
+            }
+        }
+        for item in &self.inventory {
+            line("inventory", &item.name);
+        }
+        line("avatar", &self.avatar);
+        line("current-room", &self.current_room().name);
+        for room in &self.visited {
+            line("visited", &self.board.room(*room).name);
+        }
+        for (character, room) in &self.last_seen {
+            line(
+                "seen",
+                &format!("{} -> {}", character, self.board.room(*room).name),
+            );
+        }
+        for (key, notes) in [
+            ("deduced", &self.notebook.deduced),
+            ("note", &self.notebook.marks),
+        ] {
+            for ((sheet, name), mark) in notes {
+                line(key, &format!("{} {} {}", sheet.as_save_str(), mark, name));
+            }
+        }
+        for answer in &self.answers {
+            line(
+                "answer",
+                &format!("{} -> {}", answer.character, answer.room),
+            );
+            for item in &answer.items {
+                line("answer-item", item);
+            }
+            line("answer-feedback", &answer.feedback.to_save_string());
+        }
+        line("solution-room", &self.board.room(self.solution.room).name);
+        line("solution-item", &self.solution.item);
+        line("solution-character", &self.solution.character);
+        out
+    }
+
+    pub fn from_save_str(save: &str) -> Result<Game, SaveError> {
+        let mut lines = save
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| match line.split_once(':') {
+                Some((key, value)) => Ok((key.trim(), value.trim())),
+                None => Err(malformed(format!(
+                    "expected `key: value`, found `{}`",
+                    line
+                ))),
+            });
+
+        match lines.next() {
+            Some(Ok((HEADER, version))) if version == SAVE_VERSION.to_string() => (),
+            Some(Ok((HEADER, version))) => {
+                return Err(SaveError::UnsupportedVersion(version.to_owned()))
+            }
+            _ => return Err(malformed("missing header")),
+        }
+
+        let mut seed = None;
+        let mut n_clue = None;
+        let mut max_clues = None;
+        let mut clues_asked = None;
+        let mut moves = None;
+        let mut revealed = None;
+        let mut seconds = None;
+        let mut status = None;
+        let mut clues_undoable = None;
+        let mut difficulty = None;
+        let mut action_points = None;
+        let mut width = None;
+        let mut height = None;
+        let mut npc_names = Vec::new();
+        let mut item_names = Vec::new();
+        let mut cells: Vec<Option<SavedRoom>> = Vec::new();
+        let mut inventory_names = Vec::new();
+        let mut avatar = None;
+        let mut current_room = None;
+        let mut visited_names = Vec::new();
+        let mut seen_names = Vec::new();
+        let mut deduced = Vec::new();
+        let mut notes = Vec::new();
+        let mut answers: Vec<SavedAnswer> = Vec::new();
+        let mut solution = (None, None, None);
+
+        for line in lines {
+            let (key, value) = line?;
+            let number = || {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| malformed(format!("`{}` is not a number", value)))
+            };
+            match key {
+                "seed" => seed = Some(number()?),
+                "clues" => n_clue = Some(number()? as usize),
+                "max-clues" => max_clues = Some(number()? as usize),
+                "clues-asked" => clues_asked = Some(number()? as usize),
+                "moves" => moves = Some(number()? as usize),
+                "revealed" => revealed = Some(yes_or_no(value)?),
+                "seconds" => seconds = Some(number()?),
+                "status" => status = Some(GameStatus::from_save_str(value)?),
+                "difficulty" => {
+                    difficulty = Some(
+                        value
+                            .parse()
+                            .map_err(|_| malformed(format!("unknown difficulty `{}`", value)))?,
+                    )
+                }
+                "action-points" => action_points = Some(number()? as usize),
+                "undoable-clues" => clues_undoable = Some(yes_or_no(value)?),
+                "width" => width = Some(number()? as usize),
+                "height" => height = Some(number()? as usize),
+                "npc" => npc_names.push(value.to_owned()),
+                "game-item" => item_names.push(value.to_owned()),
+                "room" => cells.push(Some(SavedRoom {
+                    name: value.to_owned(),
+                    description: None,
+                    exits: Vec::new(),
+                    items: Vec::new(),
+                    characters: Vec::new(),
+                })),
+                "empty" => cells.push(None),
+                "description" => last_room(&mut cells, key)?.description = Some(value.to_owned()),
+                "exit" => {
+                    let (direction, to) = value
+                        .split_once("->")
+                        .ok_or_else(|| malformed(format!("bad exit `{}`", value)))?;
+                    let direction = direction
+                        .parse()
+                        .map_err(|_| malformed(format!("bad exit `{}`", value)))?;
+                    last_room(&mut cells, key)?
+                        .exits
+                        .push((direction, to.trim().to_owned()));
+                }
+                "item" => last_room(&mut cells, key)?.items.push(value.to_owned()),
+                "character" => last_room(&mut cells, key)?
+                    .characters
+                    .push(value.to_owned()),
+                "inventory" => inventory_names.push(value.to_owned()),
+                "avatar" => avatar = Some(value.to_owned()),
+                "current-room" => current_room = Some(value.to_owned()),
+                "visited" => visited_names.push(value.to_owned()),
+                "seen" => {
+                    let (character, room) = value
+                        .split_once("->")
+                        .ok_or_else(|| malformed(format!("bad sighting `{}`", value)))?;
+                    seen_names.push((character.trim().to_owned(), room.trim().to_owned()));
+                }
+                "deduced" => deduced.push(note(value)?),
+                "note" => notes.push(note(value)?),
+                "answer" => {
+                    let (character, room) = value
+                        .split_once("->")
+                        .ok_or_else(|| malformed(format!("bad answer `{}`", value)))?;
+                    answers.push(SavedAnswer {
+                        room: room.trim().to_owned(),
+                        character: character.trim().to_owned(),
+                        items: Vec::new(),
+                        feedback: None,
+                    });
+                }
+                "answer-item" => last_answer(&mut answers, key)?.items.push(value.to_owned()),
+                "answer-feedback" => {
+                    let feedback = Feedback::from_save_str(value)
+                        .ok_or_else(|| malformed(format!("bad feedback `{}`", value)))?;
+                    last_answer(&mut answers, key)?.feedback = Some(feedback);
+                }
+                "solution-room" => solution.0 = Some(value.to_owned()),
+                "solution-item" => solution.1 = Some(value.to_owned()),
+                "solution-character" => solution.2 = Some(value.to_owned()),
+                _ => return Err(malformed(format!("unknown key `{}`", key))),
+            }
+        }
+
+        fn required<T>(value: Option<T>, key: &str) -> Result<T, SaveError> {
+            value.ok_or_else(|| malformed(format!("missing `{}`", key)))
+        }
+        fn lookup(list: &[Entity], name: &str, what: &str) -> Result<Entity, SaveError> {
+            list.iter()
+                .find(|x| x.name == name)
+                .cloned()
+                .ok_or_else(|| malformed(format!("unknown {} `{}`", what, name)))
+        }
+
+        let width = required(width, "width")?;
+        let height = required(height, "height")?;
+        let size = width
+            .checked_mul(height)
+            .ok_or_else(|| malformed(format!("a {}x{} board is too big", width, height)))?;
+        if cells.len() != size {
+            return Err(malformed(format!(
+                "expected {} cells, found {}",
+                size,
+                cells.len()
+            )));
+        }
+
+        let npcs: Vec<Entity> = npc_names.into_iter().map(Entity::new).collect();
+        let game_items: Vec<Entity> = item_names.into_iter().map(Entity::new).collect();
+
+        let avatar = required(avatar, "avatar")?;
+        let mut rooms = Vec::new();
+        let mut exits = Vec::new();
+        for cell in cells {
+            let Some(saved) = cell else {
+                rooms.push(None);
+                continue;
+            };
+            exits.push((saved.name.clone(), saved.exits));
+            let mut room = Room::new(saved.name);
+            room.description = saved.description;
+            for name in saved.items {
+                room.item_list.push(lookup(&game_items, &name, "item")?);
+            }
+            for name in saved.characters {
+                let character = if name == avatar {
+                    Entity::new(name)
+                } else {
+                    lookup(&npcs, &name, "character")?
+                };
+                room.character_list.push(character);
+            }
+            rooms.push(Some(room));
+        }
+        let mut board = Board::from_cells(width, height, rooms);
+        for (name, saved_exits) in exits {
+            let from = board
+                .find_room_by_name(&name)
+                .ok_or_else(|| malformed(format!("unknown room `{}`", name)))?;
+            board.room_mut(from).exits.clear();
+            for (direction, to) in saved_exits {
+                let to = board
+                    .find_room_by_name(&to)
+                    .ok_or_else(|| malformed(format!("unknown room `{}`", to)))?;
+                board.room_mut(from).set_exit(direction, to);
+            }
+        }
+
+        let inventory = inventory_names
+            .iter()
+            .map(|name| lookup(&game_items, name, "item"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let find_room = |name: String| {
+            board
+                .find_room_by_name(&name)
+                .ok_or_else(|| malformed(format!("unknown room `{}`", name)))
+        };
+        let current_room = find_room(required(current_room, "current-room")?)?;
+        if Entity::find_entity_by_name(&avatar, &board.room(current_room).character_list).is_none()
+        {
+            return Err(malformed(format!(
+                "`{}` is not in the current room",
+                avatar
+            )));
+        }
+        let visited = visited_names
+            .into_iter()
+            .map(find_room)
+            .collect::<Result<BTreeSet<_>, _>>()?;
+        let mut last_seen = BTreeMap::new();
+        for (character, room) in seen_names {
+            let character = lookup(&npcs, &character, "character")?.name;
+            last_seen.insert(character, find_room(room)?);
+        }
+        let solution = Solution {
+            room: find_room(required(solution.0, "solution-room")?)?,
+            item: lookup(&game_items, &required(solution.1, "solution-item")?, "item")?.name,
+            character: lookup(
+                &npcs,
+                &required(solution.2, "solution-character")?,
+                "character",
+            )?
+            .name,
+        };
+
+        let n_clue = required(n_clue, "clues")?;
+        let max_clues = required(max_clues, "max-clues")?;
+        if n_clue > max_clues {
+            return Err(malformed(format!(
+                "{} clues used out of only {}",
+                n_clue, max_clues
+            )));
+        }
+        let mut game = Game {
+            board,
+            game_items,
+            npcs,
+            inventory,
+            current_room,
+            avatar,
+            solution,
+            n_clue,
+            max_clues,
+            clues_asked: required(clues_asked, "clues-asked")?,
+            moves: required(moves, "moves")?,
+            revealed: required(revealed, "revealed")?,
+            time: Duration::from_secs(required(seconds, "seconds")?),
+            status: required(status, "status")?,
+            seed: required(seed, "seed")?,
+            history: History::default(),
+            clues_undoable: required(clues_undoable, "undoable-clues")?,
+            visited,
+            last_seen,
+            notebook: Notebook {
+                deduced: deduced.into_iter().collect(),
+                marks: notes.into_iter().collect(),
+            },
+            answers: answers
+                .into_iter()
+                .map(|answer| {
+                    Ok(Answer {
+                        feedback: required(answer.feedback, "answer-feedback")?,
+                        room: answer.room,
+                        character: answer.character,
+                        items: answer.items,
+                    })
+                })
+                .collect::<Result<_, SaveError>>()?,
+            difficulty: required(difficulty, "difficulty")?,
+            action_points,
+        };
+        game.observe();
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{command::Command, difficulty::Difficulty, scenario::Scenario};
+
+    fn game() -> Game {
+        let mut game =
+            Game::from_scenario(&Scenario::mansion(), Entity::new("You".to_owned()), 3).unwrap();
+        game.set_difficulty(Difficulty::Easy);
+        game.set_action_points(Some(30));
+        game
+    }
+
+    /// A loaded game has no history; everything else must come back.
+    fn reloaded(game: &Game) -> Game {
+        Game::from_save_str(&game.to_save_string()).unwrap()
+    }
+
+    fn without_history(game: &Game) -> Game {
+        Game {
+            history: History::default(),
+            ..game.clone()
+        }
+    }
+
+    #[test]
+    fn a_new_game_round_trips() {
+        let game = game();
+        assert_eq!(reloaded(&game), game);
+    }
+
+    #[test]
+    fn a_game_in_progress_round_trips() {
+        let mut game = game();
+        for command in [
+            Command::Clue("katie".to_owned()),
+            Command::Clue("harry".to_owned()),
+            Command::Undo,
+            Command::Mark("peter".to_owned(), Some(Mark::Suspect)),
+            Command::Mark("knife".to_owned(), Some(Mark::Cleared)),
+        ] {
+            game.apply(command).unwrap();
+        }
+        let items: Vec<String> = game
+            .current_room()
+            .item_list
+            .iter()
+            .map(|item| item.name.clone())
+            .collect();
+        for item in items {
+            game.apply(Command::Take(item)).unwrap();
+        }
+        assert_eq!(reloaded(&game), without_history(&game));
+    }
+
+    #[test]
+    fn every_kind_of_answer_round_trips() {
+        for difficulty in [Difficulty::Normal, Difficulty::Hard, Difficulty::Expert] {
+            let mut game = game();
+            game.set_difficulty(difficulty);
+            game.apply(Command::Clue("katie".to_owned())).unwrap();
+            game.apply(Command::Clue("harry".to_owned())).unwrap();
+            assert_eq!(game.answers().len(), 2);
+            assert_eq!(reloaded(&game), without_history(&game));
+        }
+    }
+
+    fn error(save: &str) -> String {
+        match Game::from_save_str(save) {
+            Ok(_) => panic!("loaded a bad save:\n{}", save),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn unknown_versions_are_rejected() {
+        let save = game().to_save_string();
+        let newer = save.replacen(
+            &format!("{}: {}", HEADER, SAVE_VERSION),
+            &format!("{}: {}", HEADER, SAVE_VERSION + 1),
+            1,
+        );
+        assert!(error(&newer).contains("unsupported save version"));
+    }
+
+    #[test]
+    fn answers_must_be_whole() {
+        let mut game = game();
+        game.apply(Command::Clue("katie".to_owned())).unwrap();
+        let save = game.to_save_string();
+        let feedback = save
+            .lines()
+            .find(|line| line.starts_with("answer-feedback:"))
+            .unwrap();
+        assert!(error(&save.replacen(feedback, "", 1)).contains("missing `answer-feedback`"));
+        assert!(
+            error(&save.replacen(feedback, "answer-feedback: count 4", 1)).contains("bad feedback")
+        );
+        let first = save.find("answer:").unwrap();
+        let orphan = format!("{}{}", &save[..first], "answer-item: Knife\n");
+        assert!(error(&orphan).contains("outside of an answer"));
+    }
+
+    #[test]
+    fn more_clues_than_allowed_are_rejected() {
+        let save = game().to_save_string().replacen("clues: 0", "clues: 99", 1);
+        assert!(error(&save).contains("99 clues used"));
+    }
+
+    #[test]
+    fn boards_too_big_to_size_are_rejected() {
+        let save =
+            game()
+                .to_save_string()
+                .replacen("width: 3", &format!("width: {}", usize::MAX), 1);
+        assert!(error(&save).contains("too big"));
+    }
+
+    #[test]
+    fn the_avatar_keeps_its_name() {
+        let game =
+            Game::from_scenario(&Scenario::mansion(), Entity::new("Detective".to_owned()), 3)
+                .unwrap();
+        let save = game.to_save_string();
+        assert!(save.contains("avatar: Detective\n"));
+        assert_eq!(Game::from_save_str(&save).unwrap().avatar, "Detective");
+    }
+
+    #[test]
+    fn the_avatar_must_be_named_and_in_the_current_room() {
+        let save = game().to_save_string();
+        assert!(error(&save.replacen("avatar: You\n", "", 1)).contains("missing `avatar`"));
+        let elsewhere = save.replacen("character: You\n", "", 1);
+        assert!(error(&elsewhere).contains("`You` is not in the current room"));
+    }
+
+    #[test]
+    fn every_counter_is_required() {
+        let save = game().to_save_string();
+        for key in [
+            "max-clues",
+            "clues-asked",
+            "moves",
+            "revealed",
+            "difficulty",
+        ] {
+            let line = save.lines().find(|line| line.starts_with(key)).unwrap();
+            let without = save.replacen(&format!("{}\n", line), "", 1);
+            assert!(error(&without).contains(&format!("missing `{}`", key)));
+        }
+    }
+
+    #[test]
+    fn the_clock_and_the_counters_behind_the_score_are_kept() {
+        let mut game = game();
+        game.pass_time(Duration::from_secs(125));
+        game.apply(Command::Clue("katie".to_owned())).unwrap();
+        game.apply(Command::Undo).unwrap();
+        game.apply(Command::Solution).unwrap();
+        let loaded = reloaded(&game);
+        assert_eq!(loaded.time(), Duration::from_secs(125));
+        assert_eq!(loaded.clues_asked, 1);
+        assert!(loaded.revealed);
+    }
+}
@@ -0,0 +1,354 @@
+//! Scenario files describe a case: the house, who is in it, what can be found
+//! and how many clues and action points the player gets.
+//!
+//! Like save files they are plain text with one `key: value` pair per line.
+//! Blank lines and lines starting with `#` are ignored. A `description:` before
+//! the first room describes the whole scenario; after that, `description:` and
+//! `exit:` lines belong to the `room:` line above them. When `width` and
+//! `height` are left out the rooms are arranged as close to a square as
+//! possible.
+
+use std::{error, fmt, fs, io, path::Path, str::FromStr};
+
+use crate::{
+    board::Layout, command::Direction, config::MAX_CLUES, entity::Entity, error::GameError,
+    game::Game,
+};
+
+/// Version of the scenario format understood by this build.
+pub const SCENARIO_VERSION: u32 = 1;
+
+const HEADER: &str = "adventure-scenario";
+
+/// The mansion the game has always been played in.
+const MANSION: &str = include_str!("../scenarios/mansion.scenario");
+
+#[derive(Debug)]
+pub enum ScenarioError {
+    Io(io::Error),
+    Malformed(String),
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScenarioError::Io(error) => write!(f, "{}", error),
+            ScenarioError::Malformed(message) => write!(f, "invalid scenario: {}", message),
+        }
+    }
+}
+
+impl error::Error for ScenarioError {}
+
+impl From<io::Error> for ScenarioError {
+    fn from(error: io::Error) -> Self {
+        ScenarioError::Io(error)
+    }
+}
+
+fn malformed(message: impl Into<String>) -> ScenarioError {
+    ScenarioError::Malformed(message.into())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scenario {
+    pub title: String,
+    pub description: Option<String>,
+    pub layout: Layout,
+    pub npcs: Vec<String>,
+    pub items: Vec<String>,
+    pub max_clues: usize,
+    /// The action points the player starts with, if the case is played with
+    /// them.
+    pub action_points: Option<usize>,
+}
+
+struct ScenarioRoom {
+    name: String,
+    description: Option<String>,
+    exits: Vec<(Direction, String)>,
+}
+
+fn last_room<'a>(
+    cells: &'a mut [Option<ScenarioRoom>],
+    key: &str,
+) -> Result<&'a mut ScenarioRoom, ScenarioError> {
+    cells
+        .last_mut()
+        .and_then(Option::as_mut)
+        .ok_or_else(|| malformed(format!("`{}` outside of a room", key)))
+}
+
+impl Scenario {
+    pub fn mansion() -> Self {
+        MANSION.parse().expect("the built-in scenario is valid")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ScenarioError> {
+        fs::read_to_string(path)?.parse()
+    }
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Scenario::mansion()
+    }
+}
+
+impl FromStr for Scenario {
+    type Err = ScenarioError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut lines = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| match line.split_once(':') {
+                Some((key, value)) => Ok((key.trim(), value.trim())),
+                None => Err(malformed(format!(
+                    "expected `key: value`, found `{}`",
+                    line
+                ))),
+            });
+
+        match lines.next() {
+            Some(Ok((HEADER, version))) if version == SCENARIO_VERSION.to_string() => (),
+            Some(Ok((HEADER, version))) => {
+                return Err(malformed(format!("unsupported version `{}`", version)))
+            }
+            _ => return Err(malformed("missing header")),
+        }
+
+        let mut title = None;
+        let mut description = None;
+        let mut max_clues = MAX_CLUES;
+        let mut action_points = None;
+        let mut width = None;
+        let mut height = None;
+        let mut npcs = Vec::new();
+        let mut items = Vec::new();
+        let mut cells: Vec<Option<ScenarioRoom>> = Vec::new();
+
+        for line in lines {
+            let (key, value) = line?;
+            let number = || {
+                value
+                    .parse::<usize>()
+                    .map_err(|_| malformed(format!("`{}` is not a number", value)))
+            };
+            match key {
+                "title" => title = Some(value.to_owned()),
+                "description" if cells.is_empty() => description = Some(value.to_owned()),
+                "description" => last_room(&mut cells, key)?.description = Some(value.to_owned()),
+                "max-clues" => max_clues = number()?,
+                "action-points" => action_points = Some(number()?),
+                "width" => width = Some(number()?),
+                "height" => height = Some(number()?),
+                "npc" => npcs.push(value.to_owned()),
+                "game-item" => items.push(value.to_owned()),
+                "room" => cells.push(Some(ScenarioRoom {
+                    name: value.to_owned(),
+                    description: None,
+                    exits: Vec::new(),
+                })),
+                "empty" => cells.push(None),
+                "exit" => {
+                    let (direction, to) = value
+                        .split_once("->")
+                        .ok_or_else(|| malformed(format!("bad exit `{}`", value)))?;
+                    let direction = direction
+                        .parse()
+                        .map_err(|_| malformed(format!("bad exit `{}`", value)))?;
+                    last_room(&mut cells, key)?
+                        .exits
+                        .push((direction, to.trim().to_owned()));
+                }
+                _ => return Err(malformed(format!("unknown key `{}`", key))),
+            }
+        }
+
+        let rooms: Vec<&ScenarioRoom> = cells.iter().flatten().collect();
+        if rooms.is_empty() {
+            return Err(malformed("no rooms"));
+        }
+        if npcs.is_empty() {
+            return Err(malformed("no characters"));
+        }
+        if items.is_empty() {
+            return Err(malformed("no items"));
+        }
+        if max_clues == 0 {
+            return Err(malformed("`max-clues` must be at least 1"));
+        }
+        if action_points == Some(0) {
+            return Err(malformed("`action-points` must be at least 1"));
+        }
+        // names are matched case-insensitively in game, so they must be unique
+        // regardless of case
+        let names = [
+            ("room", rooms.iter().map(|room| room.name.clone()).collect()),
+            ("character", npcs.clone()),
+            ("item", items.clone()),
+        ];
+        for (what, list) in names {
+            for (i, name) in list.iter().enumerate() {
+                if list[..i]
+                    .iter()
+                    .any(|other| other.eq_ignore_ascii_case(name))
+                {
+                    return Err(malformed(format!("{} `{}` appears twice", what, name)));
+                }
+            }
+        }
+
+        let invalid = |error: GameError| malformed(error.to_string());
+        let mut layout = match (width, height) {
+            (Some(width), Some(height)) => {
+                let names = cells
+                    .iter()
+                    .map(|cell| cell.as_ref().map(|room| room.name.clone()))
+                    .collect();
+                Layout::new(width, height, names).map_err(invalid)?
+            }
+            (None, None) if rooms.len() == cells.len() => {
+                Layout::from_names(rooms.iter().map(|room| room.name.clone()).collect())
+                    .map_err(invalid)?
+            }
+            (None, None) => return Err(malformed("`empty` cells need a `width` and `height`")),
+            _ => return Err(malformed("`width` and `height` must be given together")),
+        };
+        for room in &rooms {
+            if let Some(description) = &room.description {
+                layout
+                    .describe_room(&room.name, description)
+                    .map_err(invalid)?;
+            }
+            for (direction, to) in &room.exits {
+                if !layout.has_room(to) {
+                    return Err(malformed(format!(
+                        "exit from `{}` leads to unknown room `{}`",
+                        room.name, to
+                    )));
+                }
+                layout
+                    .add_exit(&room.name, direction.clone(), to)
+                    .map_err(invalid)?;
+            }
+        }
+
+        Ok(Scenario {
+            title: title.ok_or_else(|| malformed("missing `title`"))?,
+            description,
+            layout,
+            npcs,
+            items,
+            max_clues,
+            action_points,
+        })
+    }
+}
+
+impl Game {
+    /// Starts a new game of the given scenario.
+    pub fn from_scenario(
+        scenario: &Scenario,
+        avatar: Entity,
+        seed: u64,
+    ) -> Result<Self, GameError> {
+        let mut game = Game::with_layout(
+            scenario.layout.clone(),
+            scenario.items.iter().map(String::as_str).collect(),
+            scenario.npcs.iter().map(String::as_str).collect(),
+            avatar,
+            seed,
+        )?;
+        game.max_clues = scenario.max_clues;
+        game.action_points = scenario.action_points;
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "adventure-scenario: 1
+title: Small
+npc: Katie
+game-item: Knife
+room: Hall
+room: Attic
+";
+
+    fn error(text: &str) -> String {
+        match text.parse::<Scenario>() {
+            Ok(_) => panic!("parsed a bad scenario:\n{}", text),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn the_mansion_parses() {
+        let scenario = Scenario::mansion();
+        assert_eq!(scenario.title, "The Mansion");
+        assert_eq!(scenario.npcs.len(), 5);
+        assert_eq!(scenario.items.len(), 6);
+    }
+
+    #[test]
+    fn a_small_scenario_parses() {
+        let scenario: Scenario = SMALL.parse().unwrap();
+        assert_eq!(scenario.npcs, ["Katie"]);
+        assert_eq!(scenario.items, ["Knife"]);
+        assert_eq!(scenario.max_clues, MAX_CLUES);
+    }
+
+    #[test]
+    fn the_header_is_required() {
+        assert!(error("title: Small").contains("missing header"));
+        assert!(error("adventure-scenario: 99").contains("unsupported version `99`"));
+    }
+
+    #[test]
+    fn lines_need_a_known_key_and_a_value() {
+        assert!(error(&format!("{}nonsense\n", SMALL)).contains("expected `key: value`"));
+        assert!(error(&format!("{}colour: red\n", SMALL)).contains("unknown key `colour`"));
+        assert!(error(&format!("{}max-clues: lots\n", SMALL)).contains("`lots` is not a number"));
+    }
+
+    #[test]
+    fn a_case_needs_a_title_rooms_characters_and_items() {
+        assert!(error(&SMALL.replace("title: Small\n", "")).contains("missing `title`"));
+        assert!(error(&SMALL.replace("npc: Katie\n", "")).contains("no characters"));
+        assert!(error(&SMALL.replace("game-item: Knife\n", "")).contains("no items"));
+        let roomless = SMALL
+            .replace("room: Hall\n", "")
+            .replace("room: Attic\n", "");
+        assert!(error(&roomless).contains("no rooms"));
+    }
+
+    #[test]
+    fn names_must_be_unique_in_any_case() {
+        assert!(
+            error(&format!("{}npc: KATIE\n", SMALL)).contains("character `KATIE` appears twice")
+        );
+    }
+
+    #[test]
+    fn exits_must_lead_somewhere() {
+        let text = format!("{}exit: passage -> Cellar\n", SMALL);
+        assert!(error(&text).contains("leads to unknown room `Cellar`"));
+        assert!(error(&format!("{}exit: nowhere\n", SMALL)).contains("bad exit `nowhere`"));
+    }
+
+    #[test]
+    fn width_and_height_come_together() {
+        assert!(error(&format!("{}width: 2\n", SMALL)).contains("given together"));
+    }
+
+    #[test]
+    fn grids_too_big_to_size_are_rejected() {
+        let huge = format!("{}width: {}\nheight: 2\n", SMALL, usize::MAX);
+        assert!(error(&huge).contains("too big"));
+    }
+}
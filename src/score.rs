@@ -0,0 +1,66 @@
+//! How well a case was solved.
+//!
+//! A win starts from [`BASE`] points and loses some for every clue asked, every
+//! move made and every minute taken. The engine has no clock, so the frontend
+//! tells the game how long it is being played with [`Game::pass_time`].
+
+use crate::game::{Game, GameStatus};
+
+/// The score of a perfect game.
+pub const BASE: u64 = 1000;
+
+const PER_CLUE: u64 = 50;
+const PER_MOVE: u64 = 5;
+const PER_MINUTE: u64 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Score {
+    pub points: u64,
+    pub clues: usize,
+    pub moves: usize,
+    pub seconds: u64,
+}
+
+impl Score {
+    pub fn new(clues: usize, moves: usize, seconds: u64) -> Self {
+        let penalty = PER_CLUE * clues as u64 + PER_MOVE * moves as u64 + PER_MINUTE * seconds / 60;
+        Score {
+            points: BASE.saturating_sub(penalty),
+            clues,
+            moves,
+            seconds,
+        }
+    }
+}
+
+impl Game {
+    /// The score of a won game. A game that was lost, or whose solution was
+    /// looked up, has none.
+    pub fn score(&self) -> Option<Score> {
+        (self.status == GameStatus::Won && !self.revealed)
+            .then(|| Score::new(self.clues_asked, self.moves, self.time.as_secs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_perfect_game_scores_the_base() {
+        assert_eq!(Score::new(0, 0, 0).points, BASE);
+    }
+
+    #[test]
+    fn clues_moves_and_time_cost_points() {
+        // two and a half minutes cost two and a half minutes' worth
+        let score = Score::new(2, 3, 150);
+        assert_eq!(score.points, BASE - 2 * PER_CLUE - 3 * PER_MOVE - 25);
+        assert_eq!((score.clues, score.moves, score.seconds), (2, 3, 150));
+    }
+
+    #[test]
+    fn the_score_never_goes_below_nothing() {
+        assert_eq!(Score::new(100, 100, 60 * 60).points, 0);
+    }
+}
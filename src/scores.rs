@@ -0,0 +1,234 @@
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+};
+
+use adventure::Score;
+
+use crate::aliases::config_path;
+
+const SCORES_HEADER: &str = "adventure-scores: 1";
+
+/// How many of the best scores are kept.
+const KEPT: usize = 10;
+
+/// Where the high scores are kept: `scores` next to the config file.
+pub(crate) fn scores_path() -> Option<PathBuf> {
+    Some(config_path()?.with_file_name("scores"))
+}
+
+/// A won game in the high-score table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HighScore {
+    pub(crate) score: Score,
+    /// The title of the scenario that was solved.
+    pub(crate) scenario: String,
+}
+
+/// The best games played on this machine, best first.
+///
+/// They are stored as `score: <points>, <clues>, <moves>, <seconds>, <title>`
+/// lines and written back whenever a new one makes the table.
+pub(crate) struct HighScores {
+    path: Option<PathBuf>,
+    scores: Vec<HighScore>,
+    /// New scores are shown but kept off the table.
+    frozen: bool,
+}
+
+impl HighScores {
+    /// A table that is never saved.
+    pub(crate) fn new() -> Self {
+        HighScores {
+            path: None,
+            scores: Vec::new(),
+            frozen: false,
+        }
+    }
+
+    /// Reads the high-score file. A missing file just means nobody has won
+    /// yet.
+    pub(crate) fn load(path: PathBuf) -> Result<Self, String> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error.to_string()),
+        };
+        Ok(HighScores {
+            path: Some(path),
+            ..HighScores::parse(&contents)?
+        })
+    }
+
+    /// Reads the table from the contents of a high-score file.
+    fn parse(contents: &str) -> Result<Self, String> {
+        let mut scores = Vec::new();
+        let mut lines = contents.lines().filter(|line| !line.trim().is_empty());
+        if let Some(header) = lines.next() {
+            if header != SCORES_HEADER {
+                return Err("not an adventure scores file".to_owned());
+            }
+        }
+        for line in lines {
+            let bad = || format!("unexpected line `{}`", line);
+            let fields: Vec<&str> = line
+                .strip_prefix("score:")
+                .ok_or_else(bad)?
+                .splitn(5, ',')
+                .map(str::trim)
+                .collect();
+            let [points, clues, moves, seconds, scenario] = fields[..] else {
+                return Err(bad());
+            };
+            let number = |field: &str| field.parse::<u64>().map_err(|_| bad());
+            scores.push(HighScore {
+                score: Score {
+                    points: number(points)?,
+                    clues: number(clues)? as usize,
+                    moves: number(moves)? as usize,
+                    seconds: number(seconds)?,
+                },
+                scenario: scenario.to_owned(),
+            });
+        }
+        Ok(HighScores {
+            path: None,
+            scores,
+            frozen: false,
+        })
+    }
+
+    /// Keeps games from now on off the table, for when the player's moves
+    /// come from a file or a pipe and could have been looked up beforehand.
+    pub(crate) fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &HighScore> {
+        self.scores.iter()
+    }
+
+    /// Puts a new score in its place and saves the file. Returns its rank,
+    /// counting from 0, or `None` when it wasn't good enough to keep or the
+    /// table is frozen.
+    pub(crate) fn add(&mut self, entry: HighScore) -> Result<Option<usize>, String> {
+        if self.frozen {
+            return Ok(None);
+        }
+        // a tie goes to whoever got there first
+        let rank = self
+            .scores
+            .iter()
+            .position(|other| other.score.points < entry.score.points)
+            .unwrap_or(self.scores.len());
+        if rank >= KEPT {
+            return Ok(None);
+        }
+        self.scores.insert(rank, entry);
+        self.scores.truncate(KEPT);
+        self.save().map_err(|error| error.to_string())?;
+        Ok(Some(rank))
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.contents())
+    }
+
+    /// The table as it is written to the high-score file.
+    fn contents(&self) -> String {
+        let mut contents = format!("{}\n", SCORES_HEADER);
+        for HighScore { score, scenario } in &self.scores {
+            contents.push_str(&format!(
+                "score: {}, {}, {}, {}, {}\n",
+                score.points, score.clues, score.moves, score.seconds, scenario
+            ));
+        }
+        contents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(clues: usize, scenario: &str) -> HighScore {
+        HighScore {
+            score: Score::new(clues, 0, 0),
+            scenario: scenario.to_owned(),
+        }
+    }
+
+    fn scenarios(table: &HighScores) -> Vec<&str> {
+        table.iter().map(|entry| entry.scenario.as_str()).collect()
+    }
+
+    #[test]
+    fn scores_are_ranked_best_first_and_ties_go_to_the_first() {
+        let mut table = HighScores::new();
+        assert_eq!(table.add(entry(2, "b")), Ok(Some(0)));
+        assert_eq!(table.add(entry(0, "a")), Ok(Some(0)));
+        assert_eq!(table.add(entry(2, "c")), Ok(Some(2)));
+        assert_eq!(scenarios(&table), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn only_the_best_are_kept() {
+        let mut table = HighScores::new();
+        for clues in 0..KEPT {
+            table.add(entry(clues, "kept")).unwrap();
+        }
+        assert_eq!(table.add(entry(KEPT, "worst")), Ok(None));
+        assert_eq!(table.add(entry(0, "best")), Ok(Some(1)));
+        assert_eq!(table.iter().count(), KEPT);
+        assert_eq!(
+            table.iter().last().unwrap().score,
+            Score::new(KEPT - 2, 0, 0)
+        );
+    }
+
+    #[test]
+    fn a_frozen_table_takes_no_more_scores() {
+        let mut table = HighScores::new();
+        table.freeze();
+        assert_eq!(table.add(entry(0, "a")), Ok(None));
+        assert_eq!(table.iter().count(), 0);
+    }
+
+    #[test]
+    fn the_file_reads_back_what_was_written() {
+        let mut table = HighScores::new();
+        table.add(entry(1, "The Mansion")).unwrap();
+        table
+            .add(HighScore {
+                score: Score::new(0, 12, 300),
+                scenario: "A house, with commas".to_owned(),
+            })
+            .unwrap();
+        let read = HighScores::parse(&table.contents()).unwrap();
+        assert_eq!(read.scores, table.scores);
+    }
+
+    #[test]
+    fn an_empty_file_is_an_empty_table() {
+        assert_eq!(HighScores::parse("").unwrap().iter().count(), 0);
+    }
+
+    #[test]
+    fn bad_files_are_rejected() {
+        assert_eq!(
+            HighScores::parse("something else").err(),
+            Some("not an adventure scores file".to_owned())
+        );
+        for line in ["points: 1", "score: 1, 2, 3", "score: lots, 0, 0, 0, x"] {
+            let contents = format!("{}\n{}\n", SCORES_HEADER, line);
+            assert!(HighScores::parse(&contents).is_err(), "{}", line);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use adventure::{names_as_string, Game};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Cell, List, ListItem, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+use super::{
+    log::{self, heading},
+    App,
+};
+use crate::messages::{self, format, text};
+
+pub(super) fn draw(frame: &mut Frame, app: &App) {
+    let [panes, command] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(frame.area());
+    let [left, right] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(panes);
+    let [room, map] =
+        Layout::vertical([Constraint::Percentage(55), Constraint::Percentage(45)]).areas(left);
+    let inventory_height = app.game.inventory().len().max(1) as u16 + 2;
+    let [inventory, clues, log] = Layout::vertical([
+        Constraint::Length(inventory_height),
+        Constraint::Percentage(40),
+        Constraint::Min(3),
+    ])
+    .areas(right);
+
+    draw_room(frame, room, &app.game);
+    draw_map(frame, map, &app.game);
+    draw_inventory(frame, inventory, &app.game);
+    draw_clues(frame, clues, app);
+    draw_log(frame, log, app);
+    draw_command(frame, command, app);
+}
+
+fn draw_room(frame: &mut Frame, area: Rect, game: &Game) {
+    let room = game.room_view();
+    let mut lines = vec![Line::styled(room.name.clone(), heading())];
+    if let Some(description) = &room.description {
+        lines.push(Line::raw(description.clone()));
+    }
+    lines.push(Line::raw(""));
+    lines.push(Line::styled(text("room.around"), heading()));
+    for (direction, name) in &room.exits {
+        lines.push(Line::raw(format!(
+            "  {} ({})",
+            name,
+            messages::direction(direction)
+        )));
+    }
+    lines.push(Line::styled(format("room.items", &[&room.name]), heading()));
+    lines.push(Line::raw(format!(
+        "  {}",
+        names_as_string(&room.items, text("room.no-items"))
+    )));
+    lines.push(Line::styled(
+        format("room.characters", &[&room.name]),
+        heading(),
+    ));
+    lines.push(Line::raw(format!(
+        "  {}",
+        names_as_string(&room.characters, text("room.no-characters"))
+    )));
+    let block = Block::bordered().title(text("tui.room"));
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+/// The rooms laid out as they are on the board, with the current one
+/// highlighted, the ones not visited yet dimmed and everyone under the room
+/// they were last seen in.
+fn draw_map(frame: &mut Frame, area: Rect, game: &Game) {
+    let map = game.map_view();
+    let rows = map.cells.chunks(map.width).map(|row| {
+        let height = row
+            .iter()
+            .flatten()
+            .map(|room| room.characters.len() + 1)
+            .max()
+            .unwrap_or(1);
+        let cells =
+            row.iter().map(|cell| {
+                let Some(room) = cell else {
+                    return Cell::from("");
+                };
+                let style = if room.here {
+                    Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+                } else if room.visited {
+                    Style::default()
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                let mut lines = vec![Line::styled(room.name.clone(), style)];
+                lines.extend(room.characters.iter().map(|name| {
+                    Line::styled(format!("  {}", name), Style::default().fg(Color::Cyan))
+                }));
+                Cell::from(lines)
+            });
+        Row::new(cells).height(height as u16).bottom_margin(1)
+    });
+    let widths = vec![Constraint::Ratio(1, map.width as u32); map.width];
+    let block = Block::bordered().title(text("tui.map"));
+    frame.render_widget(Table::new(rows, widths).block(block), area);
+}
+
+fn draw_inventory(frame: &mut Frame, area: Rect, game: &Game) {
+    let inventory = game.inventory();
+    let items: Vec<ListItem> = if inventory.is_empty() {
+        vec![ListItem::new(text("inventory.empty"))]
+    } else {
+        inventory.into_iter().map(ListItem::new).collect()
+    };
+    let block = Block::bordered().title(text("tui.inventory"));
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+/// Every guess so far, newest first.
+fn draw_clues(frame: &mut Frame, area: Rect, app: &App) {
+    let items: Vec<ListItem> = app
+        .game
+        .answers()
+        .iter()
+        .rev()
+        .map(|clue| {
+            let items = if clue.items.is_empty() {
+                text("tui.no-items").to_owned()
+            } else {
+                clue.items.join(", ")
+            };
+            let mut answer = Vec::new();
+            for (style, part) in log::answer(&clue.feedback) {
+                answer.push(Span::raw("  "));
+                answer.push(Span::styled(part, style));
+            }
+            ListItem::new(vec![
+                Line::raw(format("tui.clue", &[&clue.character, &clue.room, &items])),
+                Line::from(answer),
+            ])
+        })
+        .collect();
+    let left = app.game.max_clues().saturating_sub(app.game.clues_used());
+    let title = format!(
+        "{} ({})",
+        text("tui.clues"),
+        format("tui.clues-left", &[&left, &app.game.max_clues()])
+    );
+    let block = Block::bordered().title(title);
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+/// The newest messages that fit, or older ones when scrolled back.
+fn draw_log(frame: &mut Frame, area: Rect, app: &App) {
+    let width = area.width.saturating_sub(2).max(1) as usize;
+    let height = area.height.saturating_sub(2) as usize;
+    let lines: Vec<Line> = app
+        .log
+        .lines
+        .iter()
+        .flat_map(|(style, line)| {
+            wrap(line, width)
+                .into_iter()
+                .map(move |part| Line::styled(part, *style))
+        })
+        .collect();
+    let end = lines.len().saturating_sub(app.scroll.min(lines.len()));
+    let start = end.saturating_sub(height);
+    let block = Block::bordered().title(text("tui.log"));
+    frame.render_widget(
+        Paragraph::new(lines[start..end].to_vec()).block(block),
+        area,
+    );
+}
+
+fn draw_command(frame: &mut Frame, area: Rect, app: &App) {
+    let line = format!("> {}", app.input);
+    let title = match app.game.action_points() {
+        Some(points) => format!(
+            "{} ({})",
+            text("tui.command"),
+            format("tui.points-left", &[&points])
+        ),
+        None => text("tui.command").to_owned(),
+    };
+    let block = Block::bordered().title(title);
+    frame.render_widget(Paragraph::new(line.clone()).block(block), area);
+    let x = area.x + 1 + line.chars().count() as u16;
+    frame.set_cursor_position((x.min(area.right().saturating_sub(2)), area.y + 1));
+}
+
+/// Breaks `line` into pieces no wider than `width`, between words where it
+/// can.
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    for word in line.split(' ') {
+        let fits = current.chars().count() + 1 + word.chars().count() <= width;
+        if !current.is_empty() && !fits {
+            parts.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+        while current.chars().count() > width {
+            let rest = current.split_off(current.char_indices().nth(width).map_or(0, |(i, _)| i));
+            parts.push(std::mem::replace(&mut current, rest));
+        }
+    }
+    parts.push(current);
+    parts
+}
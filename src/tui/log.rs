@@ -0,0 +1,256 @@
+use adventure::{
+    names_as_string, Event, Feedback, GameError, Part, RoomView, Scenario, Score, State, COMMANDS,
+};
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::{
+    help_menus::FRONTEND_COMMANDS,
+    messages::{self, format, text},
+    render::{error_message, marked_message, part_key, score_message, time, Renderer},
+    scores::HighScores,
+};
+
+/// Collects what the game says into the message log instead of printing it.
+/// The room, map, inventory and clue panes are drawn from the game itself, so
+/// events about them only leave a short note here.
+#[derive(Default)]
+pub(super) struct Log {
+    pub(super) lines: Vec<(Style, String)>,
+}
+
+pub(super) fn good() -> Style {
+    Style::default().fg(Color::Green)
+}
+
+pub(super) fn bad() -> Style {
+    Style::default().fg(Color::Red)
+}
+
+pub(super) fn heading() -> Style {
+    Style::default().add_modifier(Modifier::BOLD)
+}
+
+impl Log {
+    pub(super) fn push(&mut self, style: Style, line: impl Into<String>) {
+        let line = line.into();
+        if !line.trim().is_empty() {
+            self.lines.push((style, line.trim().to_owned()));
+        }
+    }
+}
+
+pub(super) fn state(state: State) -> (Style, &'static str) {
+    match state {
+        State::Match => (good(), text("answer.match")),
+        State::Miss => (bad(), text("answer.miss")),
+    }
+}
+
+/// The parts of an answer the player gets to see, each in the style of
+/// whether it matched.
+pub(super) fn answer(feedback: &Feedback) -> Vec<(Style, String)> {
+    match *feedback {
+        Feedback::Each(result) => [
+            (Part::Room, result.room_match),
+            (Part::Item, result.item_match),
+            (Part::Character, result.character_match),
+        ]
+        .into_iter()
+        .map(|(part, match_)| {
+            let (style, word) = state(match_);
+            (style, format(part_key(part), &[&word]))
+        })
+        .collect(),
+        Feedback::Count(n) => vec![(Style::default(), format("answer.count", &[&n]))],
+        Feedback::One(part, match_) => {
+            let (style, word) = state(match_);
+            vec![(style, format(part_key(part), &[&word]))]
+        }
+    }
+}
+
+impl Renderer for Log {
+    fn banner(&mut self, scenario: &Scenario, seed: u64, _max_clues: usize) {
+        self.push(heading(), text("banner.welcome"));
+        self.push(
+            Style::default(),
+            format("banner.title", &[&scenario.title, &seed]),
+        );
+        if let Some(description) = &scenario.description {
+            self.push(Style::default(), description.as_str());
+        }
+        self.push(Style::default(), text("tui.keys"));
+    }
+
+    fn room(&mut self, room: &RoomView) {
+        self.push(heading(), room.name.as_str());
+    }
+
+    fn event(&mut self, event: &Event) {
+        match event {
+            Event::Help => {
+                for info in COMMANDS.iter().filter(|info| !info.secret) {
+                    let syntax = text(&format!("syntax.{}", info.name));
+                    let description = text(&format!("help.{}", info.name));
+                    self.push(Style::default(), format!("{} - {}", syntax, description));
+                }
+                for name in FRONTEND_COMMANDS {
+                    let syntax = text(&format!("syntax.{}", name));
+                    let description = text(&format!("help.{}", name));
+                    self.push(Style::default(), format!("{} - {}", syntax, description));
+                }
+                self.push(heading(), text("tui.keys"));
+            }
+            Event::List {
+                rooms,
+                items,
+                characters,
+            } => {
+                for (title, names) in [
+                    ("list.rooms", rooms),
+                    ("list.items", items),
+                    ("list.characters", characters),
+                ] {
+                    self.push(
+                        Style::default(),
+                        format!("{}: {}", text(title), names.join(", ")),
+                    );
+                }
+            }
+            Event::Room(room) => self.room(room),
+            // the map pane already shows it
+            Event::Map(_) => {}
+            Event::Notes(notes) => {
+                for (title, lines) in [
+                    ("list.rooms", &notes.rooms),
+                    ("list.items", &notes.items),
+                    ("list.characters", &notes.characters),
+                ] {
+                    let lines: Vec<String> = lines
+                        .iter()
+                        .map(|(name, mark)| match mark {
+                            Some(mark) => format!("[{}] {}", mark, name),
+                            None => format!("[ ] {}", name),
+                        })
+                        .collect();
+                    self.push(
+                        Style::default(),
+                        format!("{}: {}", text(title), lines.join(", ")),
+                    );
+                }
+                self.push(Style::default(), text("notes.legend"));
+            }
+            Event::Marked { name, mark } => self.push(good(), marked_message(name, *mark)),
+            Event::ItemTaken(item) => {
+                self.push(good(), format!("{} ({})", text("event.taken"), item))
+            }
+            Event::ItemDropped(item) => {
+                self.push(good(), format!("{} ({})", text("event.dropped"), item))
+            }
+            Event::Inventory(items) => self.push(
+                Style::default(),
+                format(
+                    "inventory.contents",
+                    &[&names_as_string(items, text("inventory.empty"))],
+                ),
+            ),
+            Event::Clue { feedback, .. } => {
+                let parts: Vec<String> =
+                    answer(feedback).into_iter().map(|(_, part)| part).collect();
+                self.push(
+                    heading(),
+                    format!("{}  {}", text("answer.title"), parts.join("  ")),
+                );
+            }
+            Event::AttemptsRemaining(n) => {
+                self.push(Style::default(), format("event.attempts", &[n]))
+            }
+            Event::Won => {
+                self.push(good(), text("event.won"));
+                self.push(heading(), text("event.game-over"));
+                self.push(Style::default(), text("tui.quit"));
+            }
+            Event::Accused {
+                character,
+                item,
+                room,
+            } => self.push(heading(), format("event.accused", &[character, item, room])),
+            Event::Lost => {
+                self.push(bad(), text("event.lost"));
+                self.push(heading(), text("event.game-over"));
+                self.push(Style::default(), text("tui.quit"));
+            }
+            Event::Exhausted => {
+                self.push(bad(), text("event.exhausted"));
+                self.push(heading(), text("event.game-over"));
+                self.push(Style::default(), text("tui.quit"));
+            }
+            Event::Solution {
+                room,
+                item,
+                character,
+            } => self.push(
+                heading(),
+                format!(
+                    "{}  {}  {}  {}",
+                    text("answer.title"),
+                    format("answer.room", &[room]),
+                    format("answer.item", &[item]),
+                    format("answer.character", &[character])
+                ),
+            ),
+            Event::Undone(command) => self.push(
+                good(),
+                format("event.undone", &[&messages::command(command)]),
+            ),
+            Event::Redone(command) => self.push(
+                good(),
+                format("event.redone", &[&messages::command(command)]),
+            ),
+            Event::Saved(name) => self.push(good(), format("event.saved", &[name])),
+            Event::Loaded(name) => self.push(good(), format("event.loaded", &[name])),
+        }
+    }
+
+    fn error(&mut self, error: &GameError) {
+        self.push(bad(), error_message(error));
+    }
+
+    fn message(&mut self, text: &str) {
+        self.push(Style::default(), text);
+    }
+
+    fn prompt(&mut self, text: &str) {
+        self.push(Style::default().fg(Color::Yellow), text);
+    }
+
+    fn score(&mut self, score: &Score, rank: Option<usize>) {
+        self.push(good(), score_message(score));
+        if let Some(rank) = rank {
+            self.push(good(), format("scores.rank", &[&(rank + 1)]));
+        }
+    }
+
+    fn scores(&mut self, scores: &HighScores) {
+        if scores.iter().next().is_none() {
+            self.push(Style::default(), text("scores.none"));
+        }
+        for (n, entry) in scores.iter().enumerate() {
+            let score = &entry.score;
+            self.push(
+                Style::default(),
+                format(
+                    "scores.entry",
+                    &[
+                        &(n + 1),
+                        &score.points,
+                        &score.clues,
+                        &score.moves,
+                        &time(score.seconds),
+                        &entry.scenario,
+                    ],
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! The full-screen frontend started with `--tui`.
+//!
+//! It plays the same [`Game`] as the line-based mode, but keeps the room, a
+//! map of the house, the inventory, the clues so far and a log of messages on
+//! screen at once. Commands are typed at the bottom; the arrow keys and a few
+//! function keys run the common ones directly.
+
+mod draw;
+mod log;
+
+use std::{io, time::Instant};
+
+use adventure::{
+    names_as_string, Command, Direction, Game, GameError, GameStatus, ParseError, Scenario, Verb,
+};
+use ratatui::{
+    crossterm::event::{self, Event as TermEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    DefaultTerminal,
+};
+
+use self::log::{bad, Log};
+use crate::{
+    aliases::Aliases,
+    commands::{alias, apply, ask_choice, chosen, game_over, go_prompt, with_choice},
+    messages::{self, format, text},
+    render::{render, Renderer},
+    scores::HighScores,
+};
+
+/// Everything on screen between two key presses.
+struct App {
+    game: Game,
+    aliases: Aliases,
+    scores: HighScores,
+    /// The title of the scenario, for the high scores.
+    title: String,
+    /// When the game's clock was last wound on.
+    clock: Instant,
+    log: Log,
+    /// What has been typed at the command line so far.
+    input: String,
+    /// How many lines the log is scrolled back from the newest one.
+    scroll: usize,
+    /// A command whose name could mean several things, waiting for the next
+    /// line to say which one.
+    choosing: Option<(Command, Vec<String>)>,
+    quit: bool,
+}
+
+/// Plays `game` full-screen until the player quits, and returns how it ended.
+pub(crate) fn run(
+    game: Game,
+    aliases: Aliases,
+    scores: HighScores,
+    scenario: &Scenario,
+    seed: u64,
+) -> io::Result<GameStatus> {
+    let mut app = App {
+        game,
+        aliases,
+        scores,
+        title: scenario.title.clone(),
+        clock: Instant::now(),
+        log: Log::default(),
+        input: String::new(),
+        scroll: 0,
+        choosing: None,
+        quit: false,
+    };
+    app.log.banner(scenario, seed, app.game.max_clues());
+    let mut terminal = ratatui::init();
+    let result = app.run(&mut terminal);
+    ratatui::restore();
+    result.map(|()| app.game.status())
+}
+
+impl App {
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.quit {
+            terminal.draw(|frame| draw::draw(frame, self))?;
+            if let TermEvent::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    self.key(key);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn key(&mut self, key: KeyEvent) {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => self.quit = true,
+            KeyCode::Char('c') if ctrl => self.quit = true,
+            KeyCode::Char('z') if ctrl => self.apply(Command::Undo),
+            KeyCode::Char('y') if ctrl => self.apply(Command::Redo),
+            KeyCode::Up => self.apply(Command::Go(Direction::North)),
+            KeyCode::Down => self.apply(Command::Go(Direction::South)),
+            KeyCode::Left => self.apply(Command::Go(Direction::West)),
+            KeyCode::Right => self.apply(Command::Go(Direction::East)),
+            KeyCode::F(1) => self.apply(Command::Help),
+            KeyCode::F(2) => self.apply(Command::List),
+            KeyCode::PageUp => self.scroll = (self.scroll + 5).min(self.log.lines.len()),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_sub(5),
+            KeyCode::Enter => {
+                let line = std::mem::take(&mut self.input);
+                self.submit(line.trim());
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) if !ctrl => self.input.push(c),
+            _ => {}
+        }
+    }
+
+    /// Runs a typed line, the same way the line-based mode would.
+    fn submit(&mut self, line: &str) {
+        if let Some((command, candidates)) = self.choosing.take() {
+            self.choose(line, command, candidates);
+            return;
+        }
+        if line.is_empty() {
+            return;
+        }
+        self.log.push(log::heading(), format!("> {}", line));
+        let line = self.aliases.expand(line);
+        if let Some(argument) = messages::alias_argument(&line) {
+            alias(&mut self.aliases, &mut self.log, argument);
+            return;
+        }
+        if messages::is_scores(&line) {
+            self.log.scores(&self.scores);
+            return;
+        }
+        match messages::translate(&line).parse::<Command>() {
+            Ok(command) => self.apply(command),
+            // say what was left out instead of asking for it
+            Err(ParseError::MissingArgument(Verb::Go)) | Err(ParseError::InvalidDirection(_)) => {
+                self.log.prompt(&go_prompt(&self.game))
+            }
+            Err(ParseError::MissingArgument(Verb::Take)) => {
+                let room = self.game.room_view();
+                let items = names_as_string(&room.items, text("room.no-items"));
+                self.log
+                    .prompt(&format!("{} {}", text("prompt.take"), items));
+            }
+            Err(ParseError::MissingArgument(Verb::Drop)) => {
+                let items = names_as_string(&self.game.inventory(), text("inventory.empty"));
+                self.log
+                    .prompt(&format!("{} {}", text("prompt.drop"), items));
+            }
+            Err(ParseError::MissingArgument(Verb::Clue)) => {
+                let characters = names_as_string(&self.game.npcs(), text("room.no-characters"));
+                self.log.prompt(&format("prompt.clue", &[&characters]));
+            }
+            Err(ParseError::MissingArgument(Verb::Accuse)) => {
+                self.log.prompt(text("prompt.accuse"))
+            }
+            Err(ParseError::MissingArgument(Verb::Mark)) => self.log.message(text("notes.legend")),
+            Err(ParseError::MissingArgument(Verb::Save)) => self.log.prompt(text("prompt.save")),
+            Err(ParseError::MissingArgument(Verb::Load)) => self.log.prompt(text("prompt.load")),
+            Err(_) => self.log.push(bad(), text("error.invalid-command")),
+        }
+    }
+
+    /// Answers the question of which name was meant, by number or by name.
+    /// A blank line gives up.
+    fn choose(&mut self, line: &str, command: Command, candidates: Vec<String>) {
+        if line.is_empty() {
+            return;
+        }
+        self.log.push(log::heading(), format!("> {}", line));
+        match chosen(line, &candidates) {
+            Some(choice) => self.apply(with_choice(command, choice)),
+            None => {
+                self.log.push(bad(), text("error.choose"));
+                self.choosing = Some((command, candidates));
+            }
+        }
+    }
+
+    fn apply(&mut self, command: Command) {
+        // anything else done in the meantime drops the question
+        self.choosing = None;
+        self.game.pass_time(self.clock.elapsed());
+        self.clock = Instant::now();
+        let result = apply(&mut self.game, command.clone(), None);
+        if let Err(GameError::Ambiguous { name, candidates }) = &result {
+            ask_choice(&mut self.log, name, candidates);
+            self.choosing = Some((command, candidates.clone()));
+            self.scroll = 0;
+            return;
+        }
+        render(&mut self.log, &self.game, &result);
+        if result.is_ok() && self.game.status() != GameStatus::InProgress {
+            game_over(&self.game, &self.title, &mut self.scores, &mut self.log);
+        }
+        self.scroll = 0;
+    }
+}